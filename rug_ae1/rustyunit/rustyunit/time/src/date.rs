@@ -1,13 +1,16 @@
 //! The [`Date`] struct and its associated `impl`s.
 
+#[cfg(feature = "formatting")]
+use alloc::string::String;
 use core::fmt;
+use core::iter::FusedIterator;
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::{FmtWriter, Formattable};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::util::{days_in_year, days_in_year_month, is_leap_year, weeks_in_year};
@@ -27,6 +30,104 @@ pub(crate) const MIN_YEAR: i32 = -9999;
 #[cfg(not(feature = "large-dates"))]
 pub(crate) const MAX_YEAR: i32 = 9999;
 
+/// A season of the year.
+#[allow(clippy::missing_docs_in_private_items)] // variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+/// A hemisphere of the Earth, used to determine the season of a given date.
+#[allow(clippy::missing_docs_in_private_items)] // variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// An iterator over dates separated by a fixed number of days, created by [`Date::iter_days`] and
+/// [`Date::iter_weeks`].
+///
+/// Iteration ends at the last date that does not exceed [`Date::MAX`]. When iterating from the
+/// back, the first value yielded is that last date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateIter {
+    /// The Julian day of the next date to be yielded from the front.
+    front: i32,
+    /// The Julian day of the next date to be yielded from the back.
+    back: i32,
+    /// The number of days between consecutive dates.
+    step: i32,
+    /// Whether all dates have been yielded.
+    exhausted: bool,
+}
+
+impl DateIter {
+    /// Create an iterator starting at `start`, advancing by `step` days.
+    const fn new(start: Date, step: i32) -> Self {
+        let front = start.to_julian_day();
+        let back = front + (Date::MAX.to_julian_day() - front) / step * step;
+        Self {
+            front,
+            back,
+            step,
+            exhausted: false,
+        }
+    }
+}
+
+impl Iterator for DateIter {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let date = Date::from_julian_day_unchecked(self.front);
+        if self.front == self.back {
+            self.exhausted = true;
+        } else {
+            self.front += self.step;
+        }
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DateIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let date = Date::from_julian_day_unchecked(self.back);
+        if self.front == self.back {
+            self.exhausted = true;
+        } else {
+            self.back -= self.step;
+        }
+        Some(date)
+    }
+}
+
+impl ExactSizeIterator for DateIter {
+    fn len(&self) -> usize {
+        if self.exhausted {
+            0
+        } else {
+            ((self.back - self.front) / self.step) as usize + 1
+        }
+    }
+}
+
+impl FusedIterator for DateIter {}
+
 /// Date in the proleptic Gregorian calendar.
 ///
 /// By default, years between ±9999 inclusive are representable. This can be expanded to ±999,999
@@ -54,12 +155,28 @@ impl fmt::Debug for Date {
 impl Date {
     /// The minimum valid `Date`.
     ///
-    /// The value of this may vary depending on the feature flags enabled.
+    /// The value of this may vary depending on the feature flags enabled. It is `-9999-01-01`, or
+    /// `-999999-01-01` with the `large-dates` feature.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month};
+    /// assert_eq!(Date::MIN.month(), Month::January);
+    /// assert_eq!(Date::MIN.day(), 1);
+    /// assert_eq!(Date::MIN.previous_day(), None);
+    /// ```
     pub const MIN: Self = Self::__from_ordinal_date_unchecked(MIN_YEAR, 1);
 
     /// The maximum valid `Date`.
     ///
-    /// The value of this may vary depending on the feature flags enabled.
+    /// The value of this may vary depending on the feature flags enabled. It is `9999-12-31`, or
+    /// `999999-12-31` with the `large-dates` feature.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month};
+    /// assert_eq!(Date::MAX.month(), Month::December);
+    /// assert_eq!(Date::MAX.day(), 31);
+    /// assert_eq!(Date::MAX.next_day(), None);
+    /// ```
     pub const MAX: Self = Self::__from_ordinal_date_unchecked(MAX_YEAR, days_in_year(MAX_YEAR));
 
     // region: constructors
@@ -136,6 +253,15 @@ impl Date {
     /// # use time::{Date, Weekday::*};
     /// assert!(Date::from_iso_week_date(2019, 53, Monday).is_err()); // 2019 doesn't have 53 weeks.
     /// ```
+    ///
+    /// This is the inverse of [`Date::to_iso_week_date`].
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*, macros::date};
+    /// let date = date!(2021 - 01 - 01);
+    /// assert_eq!(date.to_iso_week_date(), (2020, 53, Friday));
+    /// assert_eq!(Date::from_iso_week_date(2020, 53, Friday), Ok(date));
+    /// ```
     pub const fn from_iso_week_date(
         year: i32,
         week: u8,
@@ -170,6 +296,158 @@ impl Date {
         })
     }
 
+    /// Attempt to create a `Date` from the year, week, and weekday, where week 1 begins on the
+    /// first `first_day` of the year. With `first_day` set to [`Weekday::Sunday`] or
+    /// [`Weekday::Monday`], this is the inverse of [`Date::sunday_based_week`] or
+    /// [`Date::monday_based_week`] respectively.
+    ///
+    /// Week 0 is valid input and contains the days of the year before the first `first_day`. An
+    /// error is returned if the requested day lies outside of the year, which is the case for all
+    /// of week 0 when the year begins on `first_day`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*, macros::date};
+    /// // 2019-01-01 is a Tuesday.
+    /// assert_eq!(
+    ///     Date::from_week_date(2019, 1, Sunday, Sunday),
+    ///     Ok(date!(2019 - 01 - 06))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_week_date(2019, 1, Sunday, Monday),
+    ///     Ok(date!(2019 - 01 - 13))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_week_date(2019, 0, Wednesday, Monday),
+    ///     Ok(date!(2019 - 01 - 02))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_week_date(2020, 52, Thursday, Sunday),
+    ///     Ok(date!(2020 - 12 - 31))
+    /// );
+    /// assert_eq!(date!(2020 - 12 - 31).sunday_based_week(), 52);
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*};
+    /// assert!(Date::from_week_date(2019, 0, Monday, Monday).is_err()); // 2018-12-31
+    /// assert!(Date::from_week_date(2018, 0, Monday, Monday).is_err()); // 2018 begins on a Monday.
+    /// assert!(Date::from_week_date(2019, 53, Wednesday, Sunday).is_err()); // 2020-01-01
+    /// assert!(Date::from_week_date(2019, 54, Monday, Sunday).is_err());
+    /// ```
+    pub const fn from_week_date(
+        year: i32,
+        week: u8,
+        weekday: Weekday,
+        first_day: Weekday,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(year in MIN_YEAR => MAX_YEAR);
+        ensure_value_in_range!(week in 0 => 53);
+
+        let first_day = first_day.number_days_from_monday();
+        let jan_1 = Self::__from_ordinal_date_unchecked(year, 1);
+        let jan_1_days_into_week = (jan_1.weekday().number_days_from_monday() + 7 - first_day) % 7;
+        let days_into_week = (weekday.number_days_from_monday() + 7 - first_day) % 7;
+        // The ordinal of the first `first_day` of the year, which begins week 1.
+        let week_1_start = (7 - jan_1_days_into_week) % 7 + 1;
+        let ordinal = week_1_start as i16 + (week as i16 - 1) * 7 + days_into_week as i16;
+
+        if ordinal < 1 || ordinal > days_in_year(year) as i16 {
+            return Err(error::ComponentRange {
+                name: "week",
+                minimum: 0,
+                maximum: 53,
+                value: week as _,
+                conditional_range: true,
+            });
+        }
+
+        Ok(Self::__from_ordinal_date_unchecked(year, ordinal as _))
+    }
+
+    /// Attempt to create a `Date` from the `nth` occurrence of the weekday in the given month. An
+    /// error is returned if `nth` is zero or the month has fewer than `nth` occurrences of the
+    /// weekday.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month, Weekday, macros::date};
+    /// // The third Thursday of November 2021.
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(2021, Month::November, Weekday::Thursday, 3),
+    ///     Ok(date!(2021 - 11 - 18))
+    /// );
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(2021, Month::November, Weekday::Monday, 1),
+    ///     Ok(date!(2021 - 11 - 01))
+    /// );
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(2021, Month::November, Weekday::Tuesday, 5),
+    ///     Ok(date!(2021 - 11 - 30))
+    /// );
+    /// assert_eq!(date!(2021 - 11 - 18).weekday_ordinal_in_month(), 3);
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::{Date, Month, Weekday};
+    /// assert!(Date::nth_weekday_of_month(2021, Month::November, Weekday::Monday, 0).is_err());
+    /// assert!(Date::nth_weekday_of_month(2021, Month::November, Weekday::Wednesday, 5).is_err());
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(2021, Month::November, Weekday::Monday, 0)
+    ///         .unwrap_err()
+    ///         .name(),
+    ///     "nth"
+    /// );
+    /// ```
+    pub const fn nth_weekday_of_month(
+        year: i32,
+        month: Month,
+        weekday: Weekday,
+        nth: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        let first = const_try!(Self::from_calendar_date(year, month, 1));
+        let offset =
+            (weekday.number_days_from_monday() + 7 - first.weekday().number_days_from_monday()) % 7;
+        let occurrences = (days_in_year_month(year, month) - 1 - offset) / 7 + 1;
+        ensure_value_in_range!(nth conditionally in 1 => occurrences);
+
+        Ok(Self::__from_ordinal_date_unchecked(
+            year,
+            first.ordinal() + offset as u16 + (nth as u16 - 1) * 7,
+        ))
+    }
+
+    /// Attempt to create a `Date` from the last occurrence of the weekday in the given month.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month, Weekday, macros::date};
+    /// // The last Monday of May 2021.
+    /// assert_eq!(
+    ///     Date::last_weekday_of_month(2021, Month::May, Weekday::Monday),
+    ///     Ok(date!(2021 - 05 - 31))
+    /// );
+    /// assert_eq!(
+    ///     Date::last_weekday_of_month(2021, Month::February, Weekday::Friday),
+    ///     Ok(date!(2021 - 02 - 26))
+    /// );
+    /// ```
+    pub const fn last_weekday_of_month(
+        year: i32,
+        month: Month,
+        weekday: Weekday,
+    ) -> Result<Self, error::ComponentRange> {
+        let last = const_try!(Self::from_calendar_date(
+            year,
+            month,
+            days_in_year_month(year, month)
+        ));
+        let offset =
+            (last.weekday().number_days_from_monday() + 7 - weekday.number_days_from_monday()) % 7;
+
+        Ok(Self::__from_ordinal_date_unchecked(
+            year,
+            last.ordinal() - offset as u16,
+        ))
+    }
+
     /// Create a `Date` from the Julian day.
     ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
@@ -318,8 +596,87 @@ impl Date {
         (self.value & 0x1FF) as _
     }
 
-    /// Get the ISO 8601 year and week number.
-    pub(crate) const fn iso_year_week(self) -> (i32, u8) {
+    /// Get the number of days remaining in the year, not counting the current day.
+    ///
+    /// The returned value will always be in the range `0..=365`.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).days_remaining_in_year(), 364);
+    /// assert_eq!(date!(2020 - 01 - 01).days_remaining_in_year(), 365);
+    /// assert_eq!(date!(2019 - 12 - 31).days_remaining_in_year(), 0);
+    /// ```
+    pub const fn days_remaining_in_year(self) -> u16 {
+        days_in_year(self.year()) - self.ordinal()
+    }
+
+    /// Check if the date is in a leap year.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert!(date!(2020 - 06 - 01).is_in_leap_year());
+    /// assert!(!date!(2019 - 06 - 01).is_in_leap_year());
+    /// assert!(!date!(1900 - 06 - 01).is_in_leap_year());
+    /// ```
+    pub const fn is_in_leap_year(self) -> bool {
+        is_leap_year(self.year())
+    }
+
+    /// Get the calendar quarter of the date.
+    ///
+    /// The returned value will always be in the range `1..=4`. Quarters begin in January, April,
+    /// July, and October.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).quarter(), 1);
+    /// assert_eq!(date!(2019 - 03 - 31).quarter(), 1);
+    /// assert_eq!(date!(2019 - 04 - 01).quarter(), 2);
+    /// assert_eq!(date!(2019 - 12 - 31).quarter(), 4);
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        self.month().quarter()
+    }
+
+    /// Get the day of the calendar quarter.
+    ///
+    /// The returned value will always be in the range `1..=92`.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).day_of_quarter(), 1);
+    /// assert_eq!(date!(2019 - 03 - 31).day_of_quarter(), 90);
+    /// assert_eq!(date!(2020 - 03 - 31).day_of_quarter(), 91);
+    /// assert_eq!(date!(2020 - 04 - 01).day_of_quarter(), 1);
+    /// assert_eq!(date!(2019 - 12 - 31).day_of_quarter(), 92);
+    /// ```
+    pub const fn day_of_quarter(self) -> u16 {
+        let days_before_quarter = match self.quarter() {
+            1 => return self.ordinal(),
+            2 => 90,
+            3 => 181,
+            _ => 273,
+        };
+
+        self.ordinal() - days_before_quarter - is_leap_year(self.year()) as u16
+    }
+
+    /// Get the ISO 8601 year and week number. The ISO year can differ from the calendar year for
+    /// dates near the beginning or end of the year.
+    ///
+    /// This is equivalent to [`Date::to_iso_week_date`] without the weekday.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!(2019 - 10 - 04).iso_year_week(), (2019, 40));
+    /// assert_eq!(date!(2018 - 12 - 31).iso_year_week(), (2019, 1));
+    /// assert_eq!(date!(2019 - 12 - 29).iso_year_week(), (2019, 52));
+    /// assert_eq!(date!(2019 - 12 - 30).iso_year_week(), (2020, 1));
+    /// assert_eq!(date!(2020 - 12 - 31).iso_year_week(), (2020, 53));
+    /// assert_eq!(date!(2021 - 01 - 03).iso_year_week(), (2020, 53));
+    /// assert_eq!(date!(2021 - 01 - 04).iso_year_week(), (2021, 1));
+    /// ```
+    pub const fn iso_year_week(self) -> (i32, u8) {
         let (year, ordinal) = self.to_ordinal_date();
 
         match ((ordinal + 10 - self.weekday().number_from_monday() as u16) / 7) as _ {
@@ -377,12 +734,20 @@ impl Date {
 
     /// Get the year, month, and day.
     ///
+    /// This is the inverse of [`Date::from_calendar_date`].
+    ///
     /// ```rust
-    /// # use time::{macros::date, Month};
+    /// # use time::{macros::date, Date, Month};
     /// assert_eq!(
     ///     date!(2019 - 01 - 01).to_calendar_date(),
     ///     (2019, Month::January, 1)
     /// );
+    ///
+    /// let (year, month, day) = date!(2020 - 02 - 29).to_calendar_date();
+    /// assert_eq!(
+    ///     Date::from_calendar_date(year, month, day),
+    ///     Ok(date!(2020 - 02 - 29))
+    /// );
     /// ```
     pub const fn to_calendar_date(self) -> (i32, Month, u8) {
         let (month, day) = self.month_day();
@@ -391,9 +756,18 @@ impl Date {
 
     /// Get the year and ordinal day number.
     ///
+    /// This is the inverse of [`Date::from_ordinal_date`].
+    ///
     /// ```rust
-    /// # use time::macros::date;
+    /// # use time::{macros::date, Date};
     /// assert_eq!(date!(2019 - 01 - 01).to_ordinal_date(), (2019, 1));
+    ///
+    /// let (year, ordinal) = date!(2020 - 12 - 31).to_ordinal_date();
+    /// assert_eq!(ordinal, 366);
+    /// assert_eq!(
+    ///     Date::from_ordinal_date(year, ordinal),
+    ///     Ok(date!(2020 - 12 - 31))
+    /// );
     /// ```
     pub const fn to_ordinal_date(self) -> (i32, u16) {
         (self.year(), self.ordinal())
@@ -455,6 +829,195 @@ impl Date {
         }
     }
 
+    /// Get which occurrence of its weekday the date is within its month. The returned value will
+    /// always be in the range `1..=5`.
+    ///
+    /// ```rust
+    /// # use time::{Date, Duration, Month, Weekday, macros::date};
+    /// // 2019-01-17 is the third Thursday of January.
+    /// assert_eq!(date!(2019 - 01 - 17).weekday(), Weekday::Thursday);
+    /// assert_eq!(date!(2019 - 01 - 17).weekday_ordinal_in_month(), 3);
+    /// assert_eq!(date!(2019 - 01 - 01).weekday_ordinal_in_month(), 1);
+    /// assert_eq!(date!(2019 - 01 - 07).weekday_ordinal_in_month(), 1);
+    /// assert_eq!(date!(2019 - 01 - 08).weekday_ordinal_in_month(), 2);
+    /// assert_eq!(date!(2019 - 01 - 31).weekday_ordinal_in_month(), 5);
+    ///
+    /// // Stepping back that many weeks always lands on the first such weekday of the month.
+    /// for day in 1..=31 {
+    ///     let date = Date::from_calendar_date(2019, Month::January, day)?;
+    ///     let first = date - Duration::weeks(date.weekday_ordinal_in_month() as i64 - 1);
+    ///     assert_eq!(first.month(), Month::January);
+    ///     assert_eq!(first.weekday(), date.weekday());
+    ///     assert!(first.day() <= 7);
+    /// }
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn weekday_ordinal_in_month(self) -> u8 {
+        (self.day() - 1) / 7 + 1
+    }
+
+    /// Get the first date of the week containing `self`, where weeks begin on `first_day`. This is
+    /// the most recent `first_day` on or before `self`.
+    ///
+    /// ```rust
+    /// # use time::{Weekday, macros::date};
+    /// // 2019-01-02 is a Wednesday.
+    /// assert_eq!(
+    ///     date!(2019 - 01 - 02).week_start(Weekday::Monday),
+    ///     date!(2018 - 12 - 31)
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 01 - 02).week_start(Weekday::Sunday),
+    ///     date!(2018 - 12 - 30)
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 01 - 02).week_start(Weekday::Wednesday),
+    ///     date!(2019 - 01 - 02)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resulting date would be before [`Date::MIN`].
+    pub fn week_start(self, first_day: Weekday) -> Self {
+        let days_since_start = (self.weekday().number_days_from_monday() + 7
+            - first_day.number_days_from_monday())
+            % 7;
+        Self::from_julian_day(self.to_julian_day() - days_since_start as i32)
+            .expect("resulting value is out of range")
+    }
+
+    /// Get the last date of the week containing `self`, where weeks begin on `first_day`. This is
+    /// six days after [`Date::week_start`].
+    ///
+    /// ```rust
+    /// # use time::{Weekday, macros::date};
+    /// // 2019-01-02 is a Wednesday.
+    /// assert_eq!(
+    ///     date!(2019 - 01 - 02).week_end(Weekday::Monday),
+    ///     date!(2019 - 01 - 06)
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 01 - 02).week_end(Weekday::Sunday),
+    ///     date!(2019 - 01 - 05)
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 01 - 02).week_end(Weekday::Thursday),
+    ///     date!(2019 - 01 - 02)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resulting date would be after [`Date::MAX`].
+    pub fn week_end(self, first_day: Weekday) -> Self {
+        let days_until_end = (first_day.number_days_from_monday() + 6
+            - self.weekday().number_days_from_monday())
+            % 7;
+        Self::from_julian_day(self.to_julian_day() + days_until_end as i32)
+            .expect("resulting value is out of range")
+    }
+
+    /// Get the first date on or after `self` with the provided month and day. Years in which the
+    /// day does not exist, such as February 29 in a common year, are skipped.
+    ///
+    /// An error is returned if the month and day never coexist or if the resulting date would be
+    /// after [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{Month, macros::date};
+    /// assert_eq!(
+    ///     date!(2019 - 01 - 02).next_occurrence(Month::March, 1),
+    ///     Ok(date!(2019 - 03 - 01))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 03 - 01).next_occurrence(Month::March, 1),
+    ///     Ok(date!(2019 - 03 - 01))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 03 - 02).next_occurrence(Month::March, 1),
+    ///     Ok(date!(2020 - 03 - 01))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020 - 03 - 01).next_occurrence(Month::February, 29),
+    ///     Ok(date!(2024 - 02 - 29))
+    /// );
+    /// assert_eq!(
+    ///     date!(1897 - 01 - 01).next_occurrence(Month::February, 29),
+    ///     Ok(date!(1904 - 02 - 29))
+    /// );
+    /// assert!(date!(2019 - 01 - 01).next_occurrence(Month::February, 30).is_err());
+    /// ```
+    pub const fn next_occurrence(
+        self,
+        month: Month,
+        day: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        // 2000 is a leap year, so this is the longest the month can be.
+        ensure_value_in_range!(day conditionally in 1 => days_in_year_month(2000, month));
+
+        let mut year = self.year();
+        loop {
+            if day <= days_in_year_month(year, month) {
+                let date = const_try!(Self::from_calendar_date(year, month, day));
+                if date.value >= self.value {
+                    return Ok(date);
+                }
+            }
+            year += 1;
+        }
+    }
+
+    /// Get the last date on or before `self` with the provided month and day. Years in which the
+    /// day does not exist, such as February 29 in a common year, are skipped.
+    ///
+    /// An error is returned if the month and day never coexist or if the resulting date would be
+    /// before [`Date::MIN`].
+    ///
+    /// ```rust
+    /// # use time::{Month, macros::date};
+    /// assert_eq!(
+    ///     date!(2019 - 12 - 31).previous_occurrence(Month::March, 1),
+    ///     Ok(date!(2019 - 03 - 01))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 03 - 01).previous_occurrence(Month::March, 1),
+    ///     Ok(date!(2019 - 03 - 01))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 02 - 28).previous_occurrence(Month::March, 1),
+    ///     Ok(date!(2018 - 03 - 01))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020 - 02 - 28).previous_occurrence(Month::February, 29),
+    ///     Ok(date!(2016 - 02 - 29))
+    /// );
+    /// assert_eq!(
+    ///     date!(1903 - 12 - 31).previous_occurrence(Month::February, 29),
+    ///     Ok(date!(1896 - 02 - 29))
+    /// );
+    /// assert!(date!(2019 - 01 - 01).previous_occurrence(Month::April, 31).is_err());
+    /// ```
+    pub const fn previous_occurrence(
+        self,
+        month: Month,
+        day: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        // 2000 is a leap year, so this is the longest the month can be.
+        ensure_value_in_range!(day conditionally in 1 => days_in_year_month(2000, month));
+
+        let mut year = self.year();
+        loop {
+            if day <= days_in_year_month(year, month) {
+                let date = const_try!(Self::from_calendar_date(year, month, day));
+                if date.value <= self.value {
+                    return Ok(date);
+                }
+            }
+            year -= 1;
+        }
+    }
+
     /// Get the next calendar date.
     ///
     /// ```rust
@@ -520,6 +1083,45 @@ impl Date {
         }
     }
 
+    /// Get an iterator over consecutive dates, beginning with `self`. The iterator ends after
+    /// yielding [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{Date, macros::date};
+    /// let mut days = date!(2019 - 12 - 30).iter_days();
+    /// assert_eq!(days.next(), Some(date!(2019 - 12 - 30)));
+    /// assert_eq!(days.next(), Some(date!(2019 - 12 - 31)));
+    /// assert_eq!(days.next(), Some(date!(2020 - 01 - 01)));
+    /// assert_eq!(days.next_back(), Some(Date::MAX));
+    ///
+    /// let mut days = Date::MAX.previous_day().unwrap().iter_days();
+    /// assert_eq!(days.len(), 2);
+    /// assert_eq!(days.next(), Some(Date::MAX.previous_day().unwrap()));
+    /// assert_eq!(days.next(), Some(Date::MAX));
+    /// assert_eq!(days.next(), None);
+    /// ```
+    pub const fn iter_days(self) -> DateIter {
+        DateIter::new(self, 1)
+    }
+
+    /// Get an iterator over dates exactly one week apart, beginning with `self`. The iterator ends
+    /// after yielding the last such date that does not exceed [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{Date, macros::date};
+    /// let mut weeks = date!(2019 - 12 - 25).iter_weeks();
+    /// assert_eq!(weeks.next(), Some(date!(2019 - 12 - 25)));
+    /// assert_eq!(weeks.next(), Some(date!(2020 - 01 - 01)));
+    /// assert_eq!(weeks.next(), Some(date!(2020 - 01 - 08)));
+    ///
+    /// let last = Date::MAX.iter_weeks().next_back();
+    /// assert_eq!(last, Some(Date::MAX));
+    /// assert_eq!((Date::MAX - time::Duration::days(3)).iter_weeks().count(), 1);
+    /// ```
+    pub const fn iter_weeks(self) -> DateIter {
+        DateIter::new(self, 7)
+    }
+
     /// Get the Julian day for the date.
     ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
@@ -532,6 +1134,17 @@ impl Date {
     /// assert_eq!(date!(2019 - 01 - 01).to_julian_day(), 2_458_485);
     /// assert_eq!(date!(2019 - 12 - 31).to_julian_day(), 2_458_849);
     /// ```
+    ///
+    /// This is the inverse of [`Date::from_julian_day`] for every representable date.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// let (min, max) = (Date::MIN.to_julian_day(), Date::MAX.to_julian_day());
+    /// for julian_day in (min..=max).step_by(997).chain([min, max]) {
+    ///     assert_eq!(Date::from_julian_day(julian_day)?.to_julian_day(), julian_day);
+    /// }
+    /// # Ok::<_, time::Error>(())
+    /// ```
     pub const fn to_julian_day(self) -> i32 {
         let year = self.year() - 1;
         let ordinal = self.ordinal() as i32;
@@ -540,6 +1153,67 @@ impl Date {
             + div_floor!(year, 400)
             + 1_721_425
     }
+
+    /// Get the number of days from `other` to `self`. The result is positive when `self` is later
+    /// than `other`.
+    ///
+    /// This is equivalent to `(self - other).whole_days()`, without constructing a [`Duration`].
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!(2019 - 01 - 02).days_between(date!(2019 - 01 - 01)), 1);
+    /// assert_eq!(date!(2019 - 01 - 01).days_between(date!(2019 - 01 - 02)), -1);
+    /// assert_eq!(date!(2020 - 03 - 01).days_between(date!(2020 - 02 - 28)), 2);
+    /// assert_eq!(date!(2021 - 03 - 01).days_between(date!(2021 - 02 - 28)), 1);
+    /// assert_eq!(date!(2021 - 01 - 01).days_between(date!(2020 - 01 - 01)), 366);
+    /// assert_eq!(date!(2101 - 01 - 01).days_between(date!(2100 - 01 - 01)), 365);
+    /// ```
+    pub const fn days_between(self, other: Self) -> i64 {
+        self.to_julian_day() as i64 - other.to_julian_day() as i64
+    }
+
+    /// Get the meteorological season of the date in the provided hemisphere.
+    ///
+    /// Meteorological seasons consist of whole months. In the northern hemisphere, spring is March
+    /// through May, summer is June through August, autumn is September through November, and
+    /// winter is December through February. The seasons are reversed in the southern hemisphere.
+    ///
+    /// ```rust
+    /// # use time::date::{Hemisphere, Season};
+    /// # use time::macros::date;
+    /// assert_eq!(
+    ///     date!(2019 - 12 - 25).meteorological_season(Hemisphere::Northern),
+    ///     Season::Winter
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 12 - 25).meteorological_season(Hemisphere::Southern),
+    ///     Season::Summer
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 04 - 01).meteorological_season(Hemisphere::Northern),
+    ///     Season::Spring
+    /// );
+    /// assert_eq!(
+    ///     date!(2019 - 04 - 01).meteorological_season(Hemisphere::Southern),
+    ///     Season::Autumn
+    /// );
+    /// ```
+    pub const fn meteorological_season(self, hemisphere: Hemisphere) -> Season {
+        let northern = match self.month() {
+            Month::March | Month::April | Month::May => Season::Spring,
+            Month::June | Month::July | Month::August => Season::Summer,
+            Month::September | Month::October | Month::November => Season::Autumn,
+            Month::December | Month::January | Month::February => Season::Winter,
+        };
+
+        match (hemisphere, northern) {
+            (Hemisphere::Northern, season) => season,
+            (Hemisphere::Southern, Season::Spring) => Season::Autumn,
+            (Hemisphere::Southern, Season::Summer) => Season::Winter,
+            (Hemisphere::Southern, Season::Autumn) => Season::Spring,
+            (Hemisphere::Southern, Season::Winter) => Season::Summer,
+        }
+    }
     // endregion getters
 
     // region: checked arithmetic
@@ -557,10 +1231,18 @@ impl Date {
     ///
     /// # Note
     ///
-    /// This function only takes whole days into account.
+    /// This function only takes whole days into account, truncating any partial day toward zero.
     ///
     /// ```rust
     /// # use time::{Date, ext::NumericalDuration, macros::date};
+    /// assert_eq!(
+    ///     date!(2020 - 12 - 31).checked_add(12.hours()),
+    ///     Some(date!(2020 - 12 - 31))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020 - 12 - 31).checked_add((-12).hours()),
+    ///     Some(date!(2020 - 12 - 31))
+    /// );
     /// assert_eq!(Date::MAX.checked_add(23.hours()), Some(Date::MAX));
     /// assert_eq!(Date::MIN.checked_add((-23).hours()), Some(Date::MIN));
     /// assert_eq!(
@@ -600,10 +1282,18 @@ impl Date {
     ///
     /// # Note
     ///
-    /// This function only takes whole days into account.
+    /// This function only takes whole days into account, truncating any partial day toward zero.
     ///
     /// ```
     /// # use time::{Date, ext::NumericalDuration, macros::date};
+    /// assert_eq!(
+    ///     date!(2020 - 12 - 31).checked_sub(12.hours()),
+    ///     Some(date!(2020 - 12 - 31))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020 - 12 - 31).checked_sub((-12).hours()),
+    ///     Some(date!(2020 - 12 - 31))
+    /// );
     /// assert_eq!(Date::MAX.checked_sub((-23).hours()), Some(Date::MAX));
     /// assert_eq!(Date::MIN.checked_sub(23.hours()), Some(Date::MIN));
     /// assert_eq!(
@@ -628,6 +1318,193 @@ impl Date {
             None
         }
     }
+
+    /// Computes `self` shifted forward by the provided number of calendar months, returning `None`
+    /// if the resulting year is out of range. The day saturates to the last day of the resulting
+    /// month.
+    ///
+    /// ```rust
+    /// # use time::{Date, macros::date};
+    /// assert_eq!(
+    ///     date!(2020 - 01 - 31).checked_add_months(1),
+    ///     Some(date!(2020 - 02 - 29))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 01 - 31).checked_add_months(1),
+    ///     Some(date!(2021 - 02 - 28))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 11 - 15).checked_add_months(14),
+    ///     Some(date!(2023 - 01 - 15))
+    /// );
+    /// assert_eq!(Date::MAX.checked_add_months(1), None);
+    /// ```
+    pub const fn checked_add_months(self, months: i32) -> Option<Self> {
+        self.checked_add_calendar_months(months as _)
+    }
+
+    /// Computes `self` shifted backward by the provided number of calendar months, returning
+    /// `None` if the resulting year is out of range. The day saturates to the last day of the
+    /// resulting month.
+    ///
+    /// ```rust
+    /// # use time::{Date, macros::date};
+    /// assert_eq!(
+    ///     date!(2020 - 03 - 31).checked_sub_months(1),
+    ///     Some(date!(2020 - 02 - 29))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 01 - 15).checked_sub_months(2),
+    ///     Some(date!(2020 - 11 - 15))
+    /// );
+    /// assert_eq!(Date::MIN.checked_sub_months(1), None);
+    /// ```
+    pub const fn checked_sub_months(self, months: i32) -> Option<Self> {
+        self.checked_add_calendar_months(-(months as i64))
+    }
+
+    /// Computes `self` shifted forward by the provided number of calendar years, returning `None`
+    /// if the resulting year is out of range. February 29 becomes February 28 when the resulting
+    /// year is not a leap year.
+    ///
+    /// ```rust
+    /// # use time::{Date, macros::date};
+    /// assert_eq!(
+    ///     date!(2020 - 02 - 29).checked_add_years(1),
+    ///     Some(date!(2021 - 02 - 28))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020 - 02 - 29).checked_add_years(4),
+    ///     Some(date!(2024 - 02 - 29))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 11 - 15).checked_add_years(-3),
+    ///     Some(date!(2018 - 11 - 15))
+    /// );
+    /// assert_eq!(Date::MAX.checked_add_years(1), None);
+    /// ```
+    pub const fn checked_add_years(self, years: i32) -> Option<Self> {
+        self.checked_add_calendar_months(years as i64 * 12)
+    }
+
+    /// Computes `self` shifted backward by the provided number of calendar years, returning
+    /// `None` if the resulting year is out of range. February 29 becomes February 28 when the
+    /// resulting year is not a leap year.
+    ///
+    /// ```rust
+    /// # use time::{Date, macros::date};
+    /// assert_eq!(
+    ///     date!(2020 - 02 - 29).checked_sub_years(1),
+    ///     Some(date!(2019 - 02 - 28))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 11 - 15).checked_sub_years(21),
+    ///     Some(date!(2000 - 11 - 15))
+    /// );
+    /// assert_eq!(Date::MIN.checked_sub_years(1), None);
+    /// ```
+    pub const fn checked_sub_years(self, years: i32) -> Option<Self> {
+        self.checked_add_calendar_months(-(years as i64) * 12)
+    }
+
+    /// Computes `self` shifted by the provided number of calendar months, returning `None` if an
+    /// overflow occurred. If the day does not exist in the resulting month, the last day of that
+    /// month is used.
+    pub(crate) const fn checked_add_calendar_months(self, months: i64) -> Option<Self> {
+        let (year, month, day) = self.to_calendar_date();
+        let total_months =
+            const_try_opt!((year as i64 * 12 + month as i64 - 1).checked_add(months));
+        let year = total_months.div_euclid(12);
+        if year < MIN_YEAR as i64 || year > MAX_YEAR as i64 {
+            return None;
+        }
+        let year = year as i32;
+        // The month number is always in the range `1..=12`, so this cannot fail.
+        let month = match Month::from_number((total_months.rem_euclid(12) + 1) as u8) {
+            Ok(month) => month,
+            Err(_) => return None,
+        };
+        let max_day = days_in_year_month(year, month);
+
+        match Self::from_calendar_date(year, month, if day > max_day { max_day } else { day }) {
+            Ok(date) => Some(date),
+            Err(_) => None,
+        }
+    }
+
+    /// Computes `self` shifted by the provided number of business days, skipping Saturdays and
+    /// Sundays. A negative value shifts the date backward. Holidays are not taken into account.
+    ///
+    /// Adding zero days returns `self` unchanged, even if it falls on a weekend. Otherwise, a
+    /// weekend start is treated as the adjacent business day that precedes it in the direction of
+    /// travel, so one business day after a Saturday is the following Monday.
+    ///
+    /// ```rust
+    /// # use time::{Date, macros::date};
+    /// assert_eq!(
+    ///     date!(2021 - 10 - 15).add_business_days(1), // Friday
+    ///     Ok(date!(2021 - 10 - 18))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 10 - 13).add_business_days(12), // Wednesday
+    ///     Ok(date!(2021 - 10 - 29))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 10 - 13).add_business_days(-12),
+    ///     Ok(date!(2021 - 09 - 27))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 10 - 16).add_business_days(0), // Saturday
+    ///     Ok(date!(2021 - 10 - 16))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 10 - 16).add_business_days(1),
+    ///     Ok(date!(2021 - 10 - 18))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 10 - 17).add_business_days(-1), // Sunday
+    ///     Ok(date!(2021 - 10 - 15))
+    /// );
+    /// assert!(Date::MAX.add_business_days(1).is_err());
+    /// ```
+    pub const fn add_business_days(self, days: i32) -> Result<Self, error::ComponentRange> {
+        /// The number of business days in the Julian days `0..julian_day`. Julian day zero is a
+        /// Monday, so this is negative for negative Julian days.
+        const fn business_days_before(julian_day: i64) -> i64 {
+            let remainder = julian_day.rem_euclid(7);
+            julian_day.div_euclid(7) * 5 + if remainder > 5 { 5 } else { remainder }
+        }
+
+        if days == 0 {
+            return Ok(self);
+        }
+
+        let julian_day = self.to_julian_day() as i64;
+        // The index of the business day that the count starts from. A weekend is treated as the
+        // preceding Friday when moving forward and the following Monday when moving backward.
+        let start = if days > 0 {
+            business_days_before(julian_day + 1) - 1
+        } else {
+            business_days_before(julian_day)
+        };
+
+        let minimum = business_days_before(Self::MIN.to_julian_day() as i64) - start;
+        let maximum = business_days_before(Self::MAX.to_julian_day() as i64 + 1) - 1 - start;
+        if (days as i64) < minimum || (days as i64) > maximum {
+            return Err(error::ComponentRange {
+                name: "days",
+                minimum,
+                maximum,
+                value: days as _,
+                conditional_range: true,
+            });
+        }
+
+        let index = start + days as i64;
+        Ok(Self::from_julian_day_unchecked(
+            (index.div_euclid(5) * 7 + index.rem_euclid(5)) as _,
+        ))
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -705,6 +1582,52 @@ impl Date {
         }
     }
     // region: saturating arithmetic
+
+    // region: packed representation
+    /// Pack the date into a `u32`. Ordering of the packed values matches ordering of the dates.
+    ///
+    /// The day occupies the least significant 5 bits, followed by the month number in the next 4
+    /// bits. The remaining 23 bits contain the year, offset by 2<sup>20</sup> to make it
+    /// non-negative.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(
+    ///     date!(2019 - 01 - 01).to_packed_u32(),
+    ///     ((2019 + (1 << 20)) << 9) | (1 << 5) | 1
+    /// );
+    /// assert!(date!(-0001 - 12 - 31).to_packed_u32() < date!(0000 - 01 - 01).to_packed_u32());
+    /// assert!(date!(2019 - 01 - 31).to_packed_u32() < date!(2019 - 02 - 01).to_packed_u32());
+    /// ```
+    pub const fn to_packed_u32(self) -> u32 {
+        let (year, month, day) = self.to_calendar_date();
+        (((year + (1 << 20)) as u32) << 9) | ((month as u32) << 5) | day as u32
+    }
+
+    /// Unpack a date from a `u32` previously created with [`Date::to_packed_u32`].
+    ///
+    /// ```rust
+    /// # use time::{Date, macros::date};
+    /// assert_eq!(
+    ///     Date::from_packed_u32(date!(2019 - 01 - 01).to_packed_u32()),
+    ///     Ok(date!(2019 - 01 - 01))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_packed_u32(date!(-0001 - 12 - 31).to_packed_u32()),
+    ///     Ok(date!(-0001 - 12 - 31))
+    /// );
+    /// assert!(Date::from_packed_u32(0).is_err());
+    /// ```
+    pub const fn from_packed_u32(packed: u32) -> Result<Self, error::ComponentRange> {
+        let year = (packed >> 9) as i32 - (1 << 20);
+        let month = ((packed >> 5) & 0b1111) as u8;
+        let day = (packed & 0b1_1111) as u8;
+
+        ensure_value_in_range!(year in MIN_YEAR => MAX_YEAR);
+        let month = const_try!(Month::from_number(month));
+        Self::from_calendar_date(year, month, day)
+    }
+    // endregion packed representation
 }
 
 // region: attach time
@@ -812,13 +1735,126 @@ impl Date {
             const_try!(Time::from_hms_nano(hour, minute, second, nanosecond)),
         ))
     }
+
+    /// Attempt to create a [`PrimitiveDateTime`] using the existing date and the provided time,
+    /// returning `None` if the time is invalid. This is equivalent to [`Date::with_hms`], but
+    /// discards the details of the error.
+    ///
+    /// ```rust
+    /// # use time::macros::{date, datetime};
+    /// assert_eq!(
+    ///     date!(1970 - 01 - 01).with_hms_opt(1, 2, 3),
+    ///     Some(datetime!(1970 - 01 - 01 1:02:03))
+    /// );
+    /// assert_eq!(date!(1970 - 01 - 01).with_hms_opt(24, 0, 0), None);
+    /// ```
+    pub const fn with_hms_opt(self, hour: u8, minute: u8, second: u8) -> Option<PrimitiveDateTime> {
+        match self.with_hms(hour, minute, second) {
+            Ok(datetime) => Some(datetime),
+            Err(_) => None,
+        }
+    }
+
+    /// Attempt to create a [`PrimitiveDateTime`] using the existing date and the provided time,
+    /// returning `None` if the time is invalid. This is equivalent to [`Date::with_hms_nano`], but
+    /// discards the details of the error.
+    ///
+    /// ```rust
+    /// # use time::macros::{date, datetime};
+    /// assert_eq!(
+    ///     date!(1970 - 01 - 01).with_hms_nano_opt(1, 2, 3, 4),
+    ///     Some(datetime!(1970 - 01 - 01 1:02:03.000_000_004))
+    /// );
+    /// assert_eq!(
+    ///     date!(1970 - 01 - 01).with_hms_nano_opt(0, 0, 0, 1_000_000_000),
+    ///     None
+    /// );
+    /// ```
+    pub const fn with_hms_nano_opt(
+        self,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Option<PrimitiveDateTime> {
+        match self.with_hms_nano(hour, minute, second, nanosecond) {
+            Ok(datetime) => Some(datetime),
+            Err(_) => None,
+        }
+    }
 }
 // endregion attach time
 
+// region: replacement
+/// Methods that replace part of the `Date`.
+impl Date {
+    /// Replace the calendar year, preserving the month and day. An error is returned if the
+    /// resulting date does not exist, rather than adjusting the day.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(
+    ///     date!(2022 - 02 - 18).replace_year(2019),
+    ///     Ok(date!(2019 - 02 - 18))
+    /// );
+    /// assert!(date!(2022 - 02 - 18).replace_year(-1_000_000_000).is_err()); // -1_000_000_000 isn't a valid year
+    /// assert!(date!(2020 - 02 - 29).replace_year(2019).is_err()); // 2019 isn't a leap year
+    /// ```
+    pub const fn replace_year(self, year: i32) -> Result<Self, error::ComponentRange> {
+        let (_, month, day) = self.to_calendar_date();
+        Self::from_calendar_date(year, month, day)
+    }
+
+    /// Replace the month of the year, preserving the year and day. An error is returned if the
+    /// resulting date does not exist, rather than adjusting the day.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Month};
+    /// assert_eq!(
+    ///     date!(2022 - 02 - 18).replace_month(Month::January),
+    ///     Ok(date!(2022 - 01 - 18))
+    /// );
+    /// assert!(date!(2022 - 03 - 31).replace_month(Month::February).is_err()); // February 31 doesn't exist
+    /// ```
+    pub const fn replace_month(self, month: Month) -> Result<Self, error::ComponentRange> {
+        let (year, _, day) = self.to_calendar_date();
+        Self::from_calendar_date(year, month, day)
+    }
+
+    /// Replace the day of the month, preserving the year and month.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(
+    ///     date!(2022 - 02 - 18).replace_day(1),
+    ///     Ok(date!(2022 - 02 - 01))
+    /// );
+    /// assert!(date!(2022 - 02 - 18).replace_day(0).is_err()); // 0 isn't a valid day
+    /// assert!(date!(2022 - 02 - 18).replace_day(30).is_err()); // February 30 doesn't exist
+    /// ```
+    pub const fn replace_day(self, day: u8) -> Result<Self, error::ComponentRange> {
+        let (year, month, _) = self.to_calendar_date();
+        Self::from_calendar_date(year, month, day)
+    }
+}
+// endregion replacement
+
 // region: formatting & parsing
 #[cfg(feature = "formatting")]
 impl Date {
-    /// Format the `Date` using the provided [format description](crate::format_description).
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// writing the result directly into `output`. The number of bytes written is returned.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let mut buf = Vec::new();
+    /// assert_eq!(date!(2020 - 01 - 02).format_into(&mut buf, &format)?, 10);
+    /// assert_eq!(buf, b"2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -827,6 +1863,28 @@ impl Date {
         format.format_into(output, Some(self), None, None)
     }
 
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// writing the result into a [`fmt::Write`] implementor such as a `String`.
+    ///
+    /// Unlike [`format_into`](Self::format_into), this does not require the `std` feature.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let mut buf = String::new();
+    /// date!(2020 - 01 - 02).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<(), error::Format> {
+        format.format_into(&mut FmtWriter(output), Some(self), None, None)?;
+        Ok(())
+    }
+
     /// Format the `Date` using the provided [format description](crate::format_description).
     ///
     /// ```rust
@@ -857,6 +1915,25 @@ impl Date {
     ) -> Result<Self, error::Parse> {
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from an ISO 8601 week date of the form `YYYY-Www-D`. The year is the ISO
+    /// week-based year, which may differ from the calendar year near the start and end of a year.
+    ///
+    /// With the `large-dates` feature, a year with more than four digits must be preceded by a
+    /// sign, such as `+10000-W01-1`. This matches the output of
+    /// [`OffsetDateTime::format_iso_week_date`](crate::OffsetDateTime::format_iso_week_date).
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// assert_eq!(Date::parse_iso_week_date("2024-W01-1")?, date!(2024 - 01 - 01));
+    /// assert_eq!(Date::parse_iso_week_date("2020-W53-5")?, date!(2021 - 01 - 01));
+    /// assert_eq!(Date::parse_iso_week_date("2025-W01-1")?, date!(2024 - 12 - 30));
+    /// assert!(Date::parse_iso_week_date("2021-W53-1").is_err()); // 2021 has 52 weeks.
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_iso_week_date(input: &str) -> Result<Self, error::Parse> {
+        Self::parse(input, crate::format_description::ISO_WEEK_DATE)
+    }
 }
 
 impl fmt::Display for Date {
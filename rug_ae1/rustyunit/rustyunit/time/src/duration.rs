@@ -1,5 +1,9 @@
 //! The [`Duration`] struct and its associated `impl`s.
 
+#[cfg(feature = "formatting")]
+use alloc::format;
+#[cfg(feature = "formatting")]
+use alloc::string::{String, ToString};
 use core::cmp::Ordering;
 use core::convert::{TryFrom, TryInto};
 use core::fmt;
@@ -26,6 +30,20 @@ impl Default for Padding {
     }
 }
 
+/// A unit of time that a [`Duration`] can be constructed from.
+#[allow(clippy::missing_docs_in_private_items)] // variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+}
+
 /// A span of time with nanosecond precision.
 ///
 /// Each `Duration` is composed of a whole number of seconds and a fractional part represented in
@@ -51,6 +69,95 @@ impl fmt::Debug for Duration {
     }
 }
 
+/// A fixed-capacity buffer that the [`Display`](fmt::Display) output of a [`Duration`] is written
+/// to, so that the output can be padded as a whole. The longest output, that of [`Duration::MIN`],
+/// is 41 bytes.
+struct DisplayBuffer {
+    /// The bytes written so far, followed by unused space.
+    bytes: [u8; 64],
+    /// The number of bytes written.
+    len: usize,
+}
+
+impl DisplayBuffer {
+    /// Get the contents of the buffer.
+    fn as_str(&self) -> Result<&str, fmt::Error> {
+        core::str::from_utf8(&self.bytes[..self.len]).map_err(|_| fmt::Error)
+    }
+}
+
+impl fmt::Write for DisplayBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Format the `Duration` in a compact, human-readable form.
+///
+/// The output is a sequence of components, each a number immediately followed by its unit, from
+/// largest to smallest: days (`d`), hours (`h`), minutes (`m`), seconds (`s`), milliseconds
+/// (`ms`), microseconds (`µs`), and nanoseconds (`ns`). Components with a value of zero are
+/// omitted, and there is no separator between components. A negative duration is prefixed with
+/// `-`. A zero duration is rendered as `0s`. The width, fill, and alignment of the formatter are
+/// applied to the output as a whole.
+///
+/// ```rust
+/// # use time::ext::NumericalDuration;
+/// assert_eq!(90.minutes().to_string(), "1h30m");
+/// assert_eq!(45.seconds().to_string(), "45s");
+/// assert_eq!((-2).hours().to_string(), "-2h");
+/// assert_eq!(500.milliseconds().to_string(), "500ms");
+/// assert_eq!(1_500.microseconds().to_string(), "1ms500µs");
+/// assert_eq!(26.hours().to_string(), "1d2h");
+/// assert_eq!((-1.000_000_001).seconds().to_string(), "-1s1ns");
+/// assert_eq!(0.seconds().to_string(), "0s");
+/// assert_eq!(format!("{:>8}", 90.minutes()), "   1h30m");
+/// assert_eq!(format!("{:-<6}", 0.seconds()), "0s----");
+/// ```
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        if self.is_zero() {
+            return f.pad("0s");
+        }
+
+        let mut buf = DisplayBuffer {
+            bytes: [0; 64],
+            len: 0,
+        };
+        if self.is_negative() {
+            buf.write_str("-")?;
+        }
+
+        let seconds = self.seconds.unsigned_abs();
+        let nanoseconds = self.nanoseconds.unsigned_abs() as u64;
+        let components = [
+            (seconds / 86_400, "d"),
+            (seconds / 3_600 % 24, "h"),
+            (seconds / 60 % 60, "m"),
+            (seconds % 60, "s"),
+            (nanoseconds / 1_000_000, "ms"),
+            (nanoseconds / 1_000 % 1_000, "µs"),
+            (nanoseconds % 1_000, "ns"),
+        ];
+
+        for (value, unit) in components {
+            if value != 0 {
+                write!(buf, "{}{}", value, unit)?;
+            }
+        }
+
+        f.pad(buf.as_str()?)
+    }
+}
+
 impl Duration {
     // region: constants
     /// Equivalent to `0.seconds()`.
@@ -135,10 +242,14 @@ impl Duration {
     // region: is_{sign}
     /// Check if a duration is exactly zero.
     ///
+    /// This is equivalent to comparing against [`Duration::ZERO`].
+    ///
     /// ```rust
-    /// # use time::ext::NumericalDuration;
+    /// # use time::{ext::NumericalDuration, Duration};
     /// assert!(0.seconds().is_zero());
+    /// assert!(Duration::ZERO.is_zero());
     /// assert!(!1.nanoseconds().is_zero());
+    /// assert!(!(-1).nanoseconds().is_zero());
     /// ```
     pub const fn is_zero(self) -> bool {
         self.seconds == 0 && self.nanoseconds == 0
@@ -146,6 +257,8 @@ impl Duration {
 
     /// Check if a duration is negative.
     ///
+    /// A duration of zero is neither positive nor negative.
+    ///
     /// ```rust
     /// # use time::ext::NumericalDuration;
     /// assert!((-1).seconds().is_negative());
@@ -158,6 +271,8 @@ impl Duration {
 
     /// Check if a duration is positive.
     ///
+    /// A duration of zero is neither positive nor negative.
+    ///
     /// ```rust
     /// # use time::ext::NumericalDuration;
     /// assert!(1.seconds().is_positive());
@@ -227,6 +342,30 @@ impl Duration {
         Self::new_unchecked(seconds, nanoseconds)
     }
 
+    /// Create a new `Duration` with the provided seconds and nanoseconds, without normalizing
+    /// them. Returns `None` if nanoseconds is at least ±10<sup>9</sup>, or if the two components
+    /// have opposite signs.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::new_checked(1, 500_000_000), Some(1.5.seconds()));
+    /// assert_eq!(Duration::new_checked(-1, -500_000_000), Some((-1.5).seconds()));
+    /// assert_eq!(Duration::new_checked(0, -1), Some((-1).nanoseconds()));
+    /// assert_eq!(Duration::new_checked(1, 1_000_000_000), None);
+    /// assert_eq!(Duration::new_checked(1, -1), None);
+    /// assert_eq!(Duration::new_checked(-1, 1), None);
+    /// ```
+    pub const fn new_checked(seconds: i64, nanoseconds: i32) -> Option<Self> {
+        if nanoseconds <= -1_000_000_000 || nanoseconds >= 1_000_000_000 {
+            return None;
+        }
+        if (seconds > 0 && nanoseconds < 0) || (seconds < 0 && nanoseconds > 0) {
+            return None;
+        }
+
+        Some(Self::new_unchecked(seconds, nanoseconds))
+    }
+
     /// Create a new `Duration` with the given number of weeks. Equivalent to
     /// `Duration::seconds(weeks * 604_800)`.
     ///
@@ -281,6 +420,222 @@ impl Duration {
         Self::new_unchecked(seconds, 0)
     }
 
+    /// Creates a new `Duration` from the specified number of weeks represented as `f64`.
+    ///
+    /// As with [`Duration::seconds_f64`], non-finite and out-of-range values are not detected.
+    /// Use [`Duration::try_weeks_f64`] to detect these cases.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::weeks_f64(1.5), Duration::days(10) + Duration::hours(12));
+    /// assert_eq!(Duration::weeks_f64(-1.5), -(Duration::days(10) + Duration::hours(12)));
+    /// ```
+    pub fn weeks_f64(weeks: f64) -> Self {
+        Self::seconds_f64(weeks * 604_800.)
+    }
+
+    /// Creates a new `Duration` from the specified number of weeks represented as `f32`.
+    ///
+    /// As with [`Duration::seconds_f32`], non-finite and out-of-range values are not detected.
+    /// Use [`Duration::try_weeks_f32`] to detect these cases.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::weeks_f32(1.5), Duration::days(10) + Duration::hours(12));
+    /// assert_eq!(Duration::weeks_f32(-1.5), -(Duration::days(10) + Duration::hours(12)));
+    /// ```
+    pub fn weeks_f32(weeks: f32) -> Self {
+        Self::seconds_f32(weeks * 604_800.)
+    }
+
+    /// Creates a new `Duration` from the specified number of weeks represented as `f64`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_weeks_f64(1.5), Ok(Duration::days(10) + Duration::hours(12)));
+    /// assert!(Duration::try_weeks_f64(f64::NAN).is_err());
+    /// assert!(Duration::try_weeks_f64(f64::MAX).is_err());
+    /// ```
+    pub fn try_weeks_f64(weeks: f64) -> Result<Self, error::ConversionRange> {
+        Self::try_seconds_f64(weeks * 604_800.)
+    }
+
+    /// Creates a new `Duration` from the specified number of weeks represented as `f32`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_weeks_f32(1.5), Ok(Duration::days(10) + Duration::hours(12)));
+    /// assert!(Duration::try_weeks_f32(f32::NAN).is_err());
+    /// assert!(Duration::try_weeks_f32(f32::MAX).is_err());
+    /// ```
+    pub fn try_weeks_f32(weeks: f32) -> Result<Self, error::ConversionRange> {
+        Self::try_seconds_f32(weeks * 604_800.)
+    }
+
+    /// Creates a new `Duration` from the specified number of days represented as `f64`.
+    ///
+    /// As with [`Duration::seconds_f64`], non-finite and out-of-range values are not detected.
+    /// Use [`Duration::try_days_f64`] to detect these cases.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::days_f64(1.5), Duration::hours(36));
+    /// assert_eq!(Duration::days_f64(-1.5), Duration::hours(-36));
+    /// ```
+    pub fn days_f64(days: f64) -> Self {
+        Self::seconds_f64(days * 86_400.)
+    }
+
+    /// Creates a new `Duration` from the specified number of days represented as `f32`.
+    ///
+    /// As with [`Duration::seconds_f32`], non-finite and out-of-range values are not detected.
+    /// Use [`Duration::try_days_f32`] to detect these cases.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::days_f32(1.5), Duration::hours(36));
+    /// assert_eq!(Duration::days_f32(-1.5), Duration::hours(-36));
+    /// ```
+    pub fn days_f32(days: f32) -> Self {
+        Self::seconds_f32(days * 86_400.)
+    }
+
+    /// Creates a new `Duration` from the specified number of days represented as `f64`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_days_f64(1.5), Ok(Duration::hours(36)));
+    /// assert!(Duration::try_days_f64(f64::NAN).is_err());
+    /// assert!(Duration::try_days_f64(f64::MAX).is_err());
+    /// ```
+    pub fn try_days_f64(days: f64) -> Result<Self, error::ConversionRange> {
+        Self::try_seconds_f64(days * 86_400.)
+    }
+
+    /// Creates a new `Duration` from the specified number of days represented as `f32`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_days_f32(1.5), Ok(Duration::hours(36)));
+    /// assert!(Duration::try_days_f32(f32::NAN).is_err());
+    /// assert!(Duration::try_days_f32(f32::MAX).is_err());
+    /// ```
+    pub fn try_days_f32(days: f32) -> Result<Self, error::ConversionRange> {
+        Self::try_seconds_f32(days * 86_400.)
+    }
+
+    /// Creates a new `Duration` from the specified number of hours represented as `f64`.
+    ///
+    /// As with [`Duration::seconds_f64`], non-finite and out-of-range values are not detected.
+    /// Use [`Duration::try_hours_f64`] to detect these cases.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::hours_f64(1.5), Duration::minutes(90));
+    /// assert_eq!(Duration::hours_f64(-1.5), Duration::minutes(-90));
+    /// ```
+    pub fn hours_f64(hours: f64) -> Self {
+        Self::seconds_f64(hours * 3_600.)
+    }
+
+    /// Creates a new `Duration` from the specified number of hours represented as `f32`.
+    ///
+    /// As with [`Duration::seconds_f32`], non-finite and out-of-range values are not detected.
+    /// Use [`Duration::try_hours_f32`] to detect these cases.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::hours_f32(1.5), Duration::minutes(90));
+    /// assert_eq!(Duration::hours_f32(-1.5), Duration::minutes(-90));
+    /// ```
+    pub fn hours_f32(hours: f32) -> Self {
+        Self::seconds_f32(hours * 3_600.)
+    }
+
+    /// Creates a new `Duration` from the specified number of hours represented as `f64`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_hours_f64(1.5), Ok(Duration::minutes(90)));
+    /// assert!(Duration::try_hours_f64(f64::NAN).is_err());
+    /// assert!(Duration::try_hours_f64(f64::MAX).is_err());
+    /// ```
+    pub fn try_hours_f64(hours: f64) -> Result<Self, error::ConversionRange> {
+        Self::try_seconds_f64(hours * 3_600.)
+    }
+
+    /// Creates a new `Duration` from the specified number of hours represented as `f32`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_hours_f32(1.5), Ok(Duration::minutes(90)));
+    /// assert!(Duration::try_hours_f32(f32::NAN).is_err());
+    /// assert!(Duration::try_hours_f32(f32::MAX).is_err());
+    /// ```
+    pub fn try_hours_f32(hours: f32) -> Result<Self, error::ConversionRange> {
+        Self::try_seconds_f32(hours * 3_600.)
+    }
+
+    /// Creates a new `Duration` from the specified number of minutes represented as `f64`.
+    ///
+    /// As with [`Duration::seconds_f64`], non-finite and out-of-range values are not detected.
+    /// Use [`Duration::try_minutes_f64`] to detect these cases.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::minutes_f64(1.5), Duration::seconds(90));
+    /// assert_eq!(Duration::minutes_f64(-1.5), Duration::seconds(-90));
+    /// ```
+    pub fn minutes_f64(minutes: f64) -> Self {
+        Self::seconds_f64(minutes * 60.)
+    }
+
+    /// Creates a new `Duration` from the specified number of minutes represented as `f32`.
+    ///
+    /// As with [`Duration::seconds_f32`], non-finite and out-of-range values are not detected.
+    /// Use [`Duration::try_minutes_f32`] to detect these cases.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::minutes_f32(1.5), Duration::seconds(90));
+    /// assert_eq!(Duration::minutes_f32(-1.5), Duration::seconds(-90));
+    /// ```
+    pub fn minutes_f32(minutes: f32) -> Self {
+        Self::seconds_f32(minutes * 60.)
+    }
+
+    /// Creates a new `Duration` from the specified number of minutes represented as `f64`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_minutes_f64(1.5), Ok(Duration::seconds(90)));
+    /// assert!(Duration::try_minutes_f64(f64::NAN).is_err());
+    /// assert!(Duration::try_minutes_f64(f64::MAX).is_err());
+    /// ```
+    pub fn try_minutes_f64(minutes: f64) -> Result<Self, error::ConversionRange> {
+        Self::try_seconds_f64(minutes * 60.)
+    }
+
+    /// Creates a new `Duration` from the specified number of minutes represented as `f32`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_minutes_f32(1.5), Ok(Duration::seconds(90)));
+    /// assert!(Duration::try_minutes_f32(f32::NAN).is_err());
+    /// assert!(Duration::try_minutes_f32(f32::MAX).is_err());
+    /// ```
+    pub fn try_minutes_f32(minutes: f32) -> Result<Self, error::ConversionRange> {
+        Self::try_seconds_f32(minutes * 60.)
+    }
+
     /// Creates a new `Duration` from the specified number of seconds represented as `f64`.
     ///
     /// ```rust
@@ -303,6 +658,68 @@ impl Duration {
         Self::new_unchecked(seconds as _, ((seconds % 1.) * 1_000_000_000.) as _)
     }
 
+    /// Creates a new `Duration` from the specified number of seconds represented as `f64`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::try_seconds_f64(0.5), Ok(0.5.seconds()));
+    /// assert_eq!(Duration::try_seconds_f64(-0.5), Ok((-0.5).seconds()));
+    /// assert!(Duration::try_seconds_f64(f64::NAN).is_err());
+    /// assert!(Duration::try_seconds_f64(f64::INFINITY).is_err());
+    /// assert!(Duration::try_seconds_f64(1e19).is_err());
+    /// ```
+    pub fn try_seconds_f64(seconds: f64) -> Result<Self, error::ConversionRange> {
+        // `i64::MAX as f64` rounds up to 2^63, which is itself out of range. Comparisons involving
+        // `NaN` are always false, so it is rejected as well.
+        if seconds > -(i64::MAX as f64) && seconds < i64::MAX as f64 {
+            Ok(Self::seconds_f64(seconds))
+        } else {
+            Err(error::ConversionRange)
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds represented as `f32`,
+    /// returning an error if the value is not finite or cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::try_seconds_f32(0.5), Ok(0.5.seconds()));
+    /// assert_eq!(Duration::try_seconds_f32(-0.5), Ok((-0.5).seconds()));
+    /// assert!(Duration::try_seconds_f32(f32::NAN).is_err());
+    /// assert!(Duration::try_seconds_f32(f32::INFINITY).is_err());
+    /// assert!(Duration::try_seconds_f32(1e19).is_err());
+    /// ```
+    pub fn try_seconds_f32(seconds: f32) -> Result<Self, error::ConversionRange> {
+        if seconds > -(i64::MAX as f32) && seconds < i64::MAX as f32 {
+            Ok(Self::seconds_f32(seconds))
+        } else {
+            Err(error::ConversionRange)
+        }
+    }
+
+    /// Creates a new `Duration` equal to the period of the provided frequency in hertz, returning
+    /// an error if the frequency is not positive and finite or if the period cannot be
+    /// represented. As with [`Duration::seconds_f64`], the period is truncated to the nanosecond.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::from_hz(2.), Ok(Duration::milliseconds(500)));
+    /// assert_eq!(Duration::from_hz(60.), Ok(Duration::nanoseconds(16_666_666)));
+    /// assert!(Duration::from_hz(0.).is_err());
+    /// assert!(Duration::from_hz(-1.).is_err());
+    /// assert!(Duration::from_hz(f64::NAN).is_err());
+    /// assert!(Duration::from_hz(f64::INFINITY).is_err());
+    /// assert!(Duration::from_hz(1e-19).is_err());
+    /// ```
+    pub fn from_hz(hz: f64) -> Result<Self, error::ConversionRange> {
+        if hz > 0. && hz.is_finite() {
+            Self::try_seconds_f64(1. / hz)
+        } else {
+            Err(error::ConversionRange)
+        }
+    }
+
     /// Create a new `Duration` with the given number of milliseconds.
     ///
     /// ```rust
@@ -345,6 +762,120 @@ impl Duration {
         )
     }
 
+    /// Create a new `Duration` with the given number of the provided [`Unit`], returning `None`
+    /// if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{duration::Unit, Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::from_count(2, Unit::Nanoseconds), Some(2.nanoseconds()));
+    /// assert_eq!(Duration::from_count(2, Unit::Microseconds), Some(2.microseconds()));
+    /// assert_eq!(Duration::from_count(2, Unit::Milliseconds), Some(2.milliseconds()));
+    /// assert_eq!(Duration::from_count(2, Unit::Seconds), Some(2.seconds()));
+    /// assert_eq!(Duration::from_count(2, Unit::Minutes), Some(2.minutes()));
+    /// assert_eq!(Duration::from_count(2, Unit::Hours), Some(2.hours()));
+    /// assert_eq!(Duration::from_count(2, Unit::Days), Some(2.days()));
+    /// assert_eq!(Duration::from_count(-2, Unit::Weeks), Some((-2).weeks()));
+    /// assert_eq!(Duration::from_count(i64::MAX, Unit::Weeks), None);
+    /// ```
+    pub const fn from_count(count: i64, unit: Unit) -> Option<Self> {
+        Some(match unit {
+            Unit::Nanoseconds => Self::nanoseconds(count),
+            Unit::Microseconds => Self::microseconds(count),
+            Unit::Milliseconds => Self::milliseconds(count),
+            Unit::Seconds => Self::seconds(count),
+            Unit::Minutes => Self::seconds(const_try_opt!(count.checked_mul(60))),
+            Unit::Hours => Self::seconds(const_try_opt!(count.checked_mul(3_600))),
+            Unit::Days => Self::seconds(const_try_opt!(count.checked_mul(86_400))),
+            Unit::Weeks => Self::seconds(const_try_opt!(count.checked_mul(604_800))),
+        })
+    }
+
+    /// Create a new `Duration` from the sum of the provided hours, minutes, and seconds, returning
+    /// `None` if an overflow occurred. Each component may be negative, and is added with its sign.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(
+    ///     Duration::from_hms(1, 30, 15),
+    ///     Some(1.hours() + 30.minutes() + 15.seconds())
+    /// );
+    /// assert_eq!(Duration::from_hms(1, -30, 0), Some(30.minutes()));
+    /// assert_eq!(Duration::from_hms(0, 90, 0), Some(1.5.hours()));
+    /// assert_eq!(Duration::from_hms(i64::MAX, 0, 0), None);
+    /// ```
+    pub const fn from_hms(hours: i64, minutes: i64, seconds: i64) -> Option<Self> {
+        Self::from_hms_nanos(hours, minutes, seconds, 0)
+    }
+
+    /// Create a new `Duration` from the sum of the provided hours, minutes, seconds, and
+    /// nanoseconds, returning `None` if an overflow occurred. Each component may be negative, and
+    /// is added with its sign.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(
+    ///     Duration::from_hms_nanos(1, 30, 15, 500_000_000),
+    ///     Some(1.hours() + 30.minutes() + 15.5.seconds())
+    /// );
+    /// assert_eq!(
+    ///     Duration::from_hms_nanos(0, 0, 1, -1),
+    ///     Some(999_999_999.nanoseconds())
+    /// );
+    /// assert_eq!(
+    ///     Duration::from_hms_nanos(0, 0, i64::MAX, 999_999_999),
+    ///     Some(Duration::MAX)
+    /// );
+    /// assert_eq!(Duration::from_hms_nanos(0, 0, i64::MAX, 1_000_000_000), None);
+    /// ```
+    pub const fn from_hms_nanos(
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        nanoseconds: i64,
+    ) -> Option<Self> {
+        // None of the products can overflow an `i128`, nor can their sum.
+        Self::checked_nanoseconds_i128(
+            hours as i128 * 3_600_000_000_000
+                + minutes as i128 * 60_000_000_000
+                + seconds as i128 * 1_000_000_000
+                + nanoseconds as i128,
+        )
+    }
+
+    /// Create a new `Duration` from the magnitude of a [`std::time::Duration`] and a sign. The
+    /// value saturates to [`Duration::MIN`] or [`Duration::MAX`] if the magnitude is too large to
+    /// be represented.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::{NumericalDuration, NumericalStdDuration}};
+    /// assert_eq!(Duration::from_std_signed(false, 1.5.std_seconds()), 1.5.seconds());
+    /// assert_eq!(Duration::from_std_signed(true, 1.5.std_seconds()), (-1.5).seconds());
+    /// assert_eq!(Duration::from_std_signed(true, 0.std_seconds()), Duration::ZERO);
+    /// assert_eq!(
+    ///     Duration::from_std_signed(false, u64::MAX.std_seconds()),
+    ///     Duration::MAX
+    /// );
+    /// assert_eq!(
+    ///     Duration::from_std_signed(true, u64::MAX.std_seconds()),
+    ///     Duration::MIN
+    /// );
+    /// ```
+    pub const fn from_std_signed(negative: bool, std: StdDuration) -> Self {
+        // `Duration::MIN` has a whole-second magnitude one greater than `Duration::MAX`.
+        if std.as_secs() > i64::MAX as u64 + negative as u64 {
+            return if negative { Self::MIN } else { Self::MAX };
+        }
+
+        let seconds = std.as_secs() as i64;
+        let nanoseconds = std.subsec_nanos() as i32;
+        if negative {
+            // A magnitude of 2^63 seconds is cast to `i64::MIN`, which is its own negation.
+            Self::new_unchecked(seconds.wrapping_neg(), -nanoseconds)
+        } else {
+            Self::new_unchecked(seconds, nanoseconds)
+        }
+    }
+
     /// Create a new `Duration` with the given number of nanoseconds.
     ///
     /// As the input range cannot be fully mapped to the output, this should only be used where it's
@@ -355,6 +886,33 @@ impl Duration {
             (nanoseconds % 1_000_000_000) as _,
         )
     }
+
+    /// Create a new `Duration` with the given number of nanoseconds, returning `None` if the value
+    /// cannot be represented.
+    pub(crate) const fn checked_nanoseconds_i128(nanoseconds: i128) -> Option<Self> {
+        if nanoseconds < Self::MIN.whole_nanoseconds()
+            || nanoseconds > Self::MAX.whole_nanoseconds()
+        {
+            None
+        } else {
+            Some(Self::nanoseconds_i128(nanoseconds))
+        }
+    }
+
+    /// Create a new `Duration` with the given number of nanoseconds, rounded to the nearest
+    /// integer. Halfway cases are rounded away from zero. `None` is returned if the value is `NaN`
+    /// or cannot be represented.
+    fn checked_nanoseconds_f64(nanoseconds: f64) -> Option<Self> {
+        if nanoseconds.is_nan() {
+            return None;
+        }
+        // Casting to an integer truncates toward zero and saturates, including for infinities.
+        Self::checked_nanoseconds_i128(if nanoseconds < 0. {
+            (nanoseconds - 0.5) as i128
+        } else {
+            (nanoseconds + 0.5) as i128
+        })
+    }
     // endregion constructors
 
     // region: getters
@@ -445,6 +1003,45 @@ impl Duration {
         self.seconds as f32 + self.nanoseconds as f32 / 1_000_000_000.
     }
 
+    /// Get the number of fractional seconds in the duration, returning an error if the `f64`
+    /// cannot be converted back to the same duration when rounded to the nearest nanosecond.
+    ///
+    /// Any duration whose magnitude is less than 2<sup>22</sup> seconds (roughly 48 days) is
+    /// always accepted. Beyond that, nanosecond precision is progressively lost; durations of
+    /// whole seconds are accepted up to 2<sup>53</sup> seconds.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(1.5.seconds().as_seconds_f64_exact(), Ok(1.5));
+    /// assert_eq!((-1.1).seconds().as_seconds_f64_exact(), Ok(-1.1));
+    /// assert_eq!(Duration::seconds(1 << 53).as_seconds_f64_exact(), Ok(9_007_199_254_740_992.));
+    /// assert!(Duration::seconds((1 << 53) + 1).as_seconds_f64_exact().is_err());
+    /// assert!(Duration::new(1 << 40, 1).as_seconds_f64_exact().is_err());
+    /// assert!(Duration::MAX.as_seconds_f64_exact().is_err());
+    /// assert!(Duration::MIN.as_seconds_f64_exact().is_err());
+    /// ```
+    pub fn as_seconds_f64_exact(self) -> Result<f64, error::ConversionRange> {
+        let seconds = self.as_seconds_f64();
+        // Values this large saturate when cast to `i64`, which would hide the difference.
+        if seconds >= i64::MAX as f64 {
+            return Err(error::ConversionRange);
+        }
+
+        let whole_seconds = seconds as i64;
+        let nanoseconds = (seconds - whole_seconds as f64) * 1_000_000_000.;
+        let nanoseconds = if nanoseconds < 0. {
+            (nanoseconds - 0.5) as i64
+        } else {
+            (nanoseconds + 0.5) as i64
+        };
+
+        if whole_seconds as i128 * 1_000_000_000 + nanoseconds as i128 == self.whole_nanoseconds() {
+            Ok(seconds)
+        } else {
+            Err(error::ConversionRange)
+        }
+    }
+
     /// Get the number of whole milliseconds in the duration.
     ///
     /// ```rust
@@ -610,6 +1207,134 @@ impl Duration {
 
         Some(Self::new_unchecked(seconds, nanoseconds))
     }
+
+    /// Computes `-self`, returning `None` if an overflow occurred. This only happens when the
+    /// whole seconds are `i64::MIN`, such as for [`Duration::MIN`]. The [`Neg`] implementation
+    /// panics in this situation.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(5.seconds().checked_neg(), Some((-5).seconds()));
+    /// assert_eq!((-5).seconds().checked_neg(), Some(5.seconds()));
+    /// assert_eq!(Duration::ZERO.checked_neg(), Some(Duration::ZERO));
+    /// assert_eq!(Duration::MAX.checked_neg(), Some(Duration::new(-i64::MAX, -999_999_999)));
+    /// assert_eq!(Duration::MIN.checked_neg(), None);
+    /// ```
+    pub const fn checked_neg(self) -> Option<Self> {
+        if self.seconds == i64::MIN {
+            None
+        } else {
+            Some(Self::new_unchecked(-self.seconds, -self.nanoseconds))
+        }
+    }
+
+    /// Computes `self * rhs`, rounded to the nearest nanosecond and limited to the precision of an
+    /// `f64` number of seconds. `None` is returned if the result is `NaN` or overflows.
+    ///
+    /// The whole seconds and the subsecond nanoseconds are scaled separately, so the result does
+    /// not lose precision as the number of nanoseconds grows past 2<sup>53</sup>. It is exact
+    /// whenever each scaled component is exactly representable as an `f64`, such as when `rhs` is
+    /// `1.0` or a power of two.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(5.seconds().checked_mul_f64(1.5), Some(7.5.seconds()));
+    /// assert_eq!(
+    ///     Duration::new(365 * 86_400, 1).checked_mul_f64(1.),
+    ///     Some(Duration::new(365 * 86_400, 1))
+    /// );
+    /// assert_eq!(Duration::MAX.checked_mul_f64(1.), Some(Duration::MAX));
+    /// assert_eq!(5.seconds().checked_mul_f64(-0.5), Some((-2.5).seconds()));
+    /// assert_eq!(1.nanoseconds().checked_mul_f64(0.5), Some(1.nanoseconds()));
+    /// assert_eq!(1.nanoseconds().checked_mul_f64(0.49), Some(0.nanoseconds()));
+    /// assert_eq!(Duration::MAX.checked_mul_f64(2.), None);
+    /// assert_eq!(5.seconds().checked_mul_f64(f64::INFINITY), None);
+    /// assert_eq!(5.seconds().checked_mul_f64(f64::NAN), None);
+    /// assert_eq!(Duration::ZERO.checked_mul_f64(f64::INFINITY), None);
+    /// ```
+    pub fn checked_mul_f64(self, rhs: f64) -> Option<Self> {
+        self.scale_f64(rhs, |value, rhs| value * rhs).ok()
+    }
+
+    /// Computes `self / rhs`, rounded to the nearest nanosecond and limited to the precision of an
+    /// `f64` number of seconds. `None` is returned if the result is `NaN` or overflows, which
+    /// includes dividing by zero.
+    ///
+    /// As with [`Duration::checked_mul_f64`], the whole seconds and the subsecond nanoseconds are
+    /// scaled separately.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(5.seconds().checked_div_f64(2.), Some(2.5.seconds()));
+    /// assert_eq!(
+    ///     Duration::new(365 * 86_400, 2).checked_div_f64(2.),
+    ///     Some(Duration::new(365 * 43_200, 1))
+    /// );
+    /// assert_eq!(5.seconds().checked_div_f64(-0.5), Some((-10).seconds()));
+    /// assert_eq!(1.seconds().checked_div_f64(3.), Some(333_333_333.nanoseconds()));
+    /// assert_eq!(Duration::MAX.checked_div_f64(0.5), None);
+    /// assert_eq!(5.seconds().checked_div_f64(0.), None);
+    /// assert_eq!(5.seconds().checked_div_f64(f64::NAN), None);
+    /// assert_eq!(Duration::ZERO.checked_div_f64(0.), None);
+    /// ```
+    pub fn checked_div_f64(self, rhs: f64) -> Option<Self> {
+        self.scale_f64(rhs, |value, rhs| value / rhs).ok()
+    }
+
+    /// Apply `op` to the whole seconds and the subsecond nanoseconds of `self` separately, rounding
+    /// the sum to the nearest nanosecond.
+    ///
+    /// If the result cannot be represented, the scaled value of a nonzero component is returned as
+    /// the error. It is `NaN` if the result is not a number, and otherwise has the sign of the
+    /// result.
+    fn scale_f64(self, rhs: f64, op: fn(f64, f64) -> f64) -> Result<Self, f64> {
+        // A zero component contributes nothing, even when `rhs` is infinite or zero. Only when all
+        // of them are zero can an infinite or zero `rhs` make the result `NaN`.
+        if self.is_zero() {
+            let scaled = op(0., rhs);
+            return if scaled.is_nan() {
+                Err(scaled)
+            } else {
+                Ok(Self::ZERO)
+            };
+        }
+
+        // The whole seconds are split in two so that each part is exactly representable as an
+        // `f64`, even when the duration is close to its minimum or maximum. All components share
+        // the sign of the duration.
+        let low_seconds = self.seconds % (1 << 32);
+        let mut whole_seconds = 0_i128;
+        let mut nanoseconds = 0.;
+        let mut last_scaled = 0.;
+        for &part in &[self.seconds - low_seconds, low_seconds] {
+            if part == 0 {
+                continue;
+            }
+            let seconds = op(part as f64, rhs);
+            if !seconds.is_finite() {
+                return Err(seconds);
+            }
+            // Casting to an integer truncates toward zero, so the subtraction below is exact.
+            let whole = seconds as i128;
+            whole_seconds = whole_seconds.checked_add(whole).ok_or(seconds)?;
+            nanoseconds += (seconds - whole as f64) * 1_000_000_000.;
+            last_scaled = seconds;
+        }
+        if self.nanoseconds != 0 {
+            let scaled = op(self.nanoseconds as f64, rhs);
+            if !scaled.is_finite() {
+                return Err(scaled);
+            }
+            nanoseconds += scaled;
+            last_scaled = scaled;
+        }
+
+        whole_seconds
+            .checked_mul(1_000_000_000)
+            .and_then(Self::checked_nanoseconds_i128)
+            .and_then(|whole| whole.checked_add(Self::checked_nanoseconds_f64(nanoseconds)?))
+            .ok_or(last_scaled)
+    }
     // endregion checked arithmetic
 
     // region: saturating arithmetic
@@ -725,8 +1450,219 @@ impl Duration {
 
         Self::new_unchecked(seconds, nanoseconds)
     }
+
+    /// Computes `-self`, saturating to [`Duration::MAX`] if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(5.seconds().saturating_neg(), (-5).seconds());
+    /// assert_eq!((-5).seconds().saturating_neg(), 5.seconds());
+    /// assert_eq!(Duration::MAX.saturating_neg(), Duration::new(-i64::MAX, -999_999_999));
+    /// assert_eq!(Duration::MIN.saturating_neg(), Duration::MAX);
+    /// ```
+    pub const fn saturating_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(duration) => duration,
+            None => Self::MAX,
+        }
+    }
+
+    /// Computes `self * rhs`, rounded to the nearest nanosecond, limited to the precision of an
+    /// `f64` number of seconds, and saturating if an overflow occurred.
+    ///
+    /// The precision is the same as that of [`Duration::checked_mul_f64`]. Unlike the [`Mul`]
+    /// implementation, the result is rounded rather than truncated, and an overflow is handled
+    /// explicitly.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `rhs` is `NaN`. Multiplying [`Duration::ZERO`] by an infinite value returns
+    /// [`Duration::ZERO`]; use [`Duration::checked_mul_f64`] to detect this case instead.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(5.seconds().mul_f64(1.5), 7.5.seconds());
+    /// assert_eq!(
+    ///     Duration::new(365 * 86_400, 1).mul_f64(1.),
+    ///     Duration::new(365 * 86_400, 1)
+    /// );
+    /// assert_eq!(5.seconds().mul_f64(-0.5), (-2.5).seconds());
+    /// assert_eq!(Duration::MAX.mul_f64(2.), Duration::MAX);
+    /// assert_eq!(Duration::MAX.mul_f64(-2.), Duration::MIN);
+    /// assert_eq!(5.seconds().mul_f64(f64::INFINITY), Duration::MAX);
+    /// assert_eq!((-5).seconds().mul_f64(f64::INFINITY), Duration::MIN);
+    /// assert_eq!(Duration::ZERO.mul_f64(f64::INFINITY), Duration::ZERO);
+    /// ```
+    pub fn mul_f64(self, rhs: f64) -> Self {
+        self.saturating_scale_f64(rhs, |value, rhs| value * rhs)
+    }
+
+    /// Computes `self / rhs`, rounded to the nearest nanosecond, limited to the precision of an
+    /// `f64` number of seconds, and saturating if an overflow occurred. Dividing a nonzero duration
+    /// by zero saturates in the direction of its sign.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `rhs` is `NaN`. Dividing [`Duration::ZERO`] by zero returns
+    /// [`Duration::ZERO`]; use [`Duration::checked_div_f64`] to detect this case instead.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(5.seconds().div_f64(2.), 2.5.seconds());
+    /// assert_eq!(1.seconds().div_f64(3.), 333_333_333.nanoseconds());
+    /// assert_eq!(
+    ///     Duration::new(365 * 86_400, 2).div_f64(2.),
+    ///     Duration::new(365 * 43_200, 1)
+    /// );
+    /// assert_eq!(Duration::MIN.div_f64(0.5), Duration::MIN);
+    /// assert_eq!(5.seconds().div_f64(0.), Duration::MAX);
+    /// assert_eq!(5.seconds().div_f64(-0.), Duration::MIN);
+    /// assert_eq!(Duration::ZERO.div_f64(0.), Duration::ZERO);
+    /// ```
+    pub fn div_f64(self, rhs: f64) -> Self {
+        self.saturating_scale_f64(rhs, |value, rhs| value / rhs)
+    }
+
+    /// Apply `op` to the whole seconds and the subsecond nanoseconds of `self` separately, rounding
+    /// the sum to the nearest nanosecond and saturating if it cannot be represented. A `NaN` result
+    /// from scaling [`Duration::ZERO`] is treated as zero.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `rhs` is `NaN`.
+    fn saturating_scale_f64(self, rhs: f64, op: fn(f64, f64) -> f64) -> Self {
+        assert!(!rhs.is_nan(), "duration is not a number");
+        match self.scale_f64(rhs, op) {
+            Ok(duration) => duration,
+            Err(scaled) if scaled.is_nan() => Self::ZERO,
+            Err(scaled) if scaled < 0. => Self::MIN,
+            Err(_) => Self::MAX,
+        }
+    }
     // endregion saturating arithmetic
 
+    // region: rounding
+    /// Round the duration to the nearest multiple of `unit`, with ties rounding away from zero. The
+    /// sign of `unit` is ignored, and a zero `unit` returns the duration unchanged.
+    ///
+    /// If the rounded value would exceed the representable range, the result saturates to
+    /// [`Duration::MIN`] or [`Duration::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(1_500.milliseconds().round_to(1.seconds()), 2.seconds());
+    /// assert_eq!(1_499.milliseconds().round_to(1.seconds()), 1.seconds());
+    /// assert_eq!((-1_500).milliseconds().round_to(1.seconds()), (-2).seconds());
+    /// assert_eq!(89.seconds().round_to(1.minutes()), 1.minutes());
+    /// assert_eq!(90.seconds().round_to(1.minutes()), 2.minutes());
+    /// assert_eq!(1.5.seconds().round_to(Duration::ZERO), 1.5.seconds());
+    /// assert_eq!(Duration::MAX.round_to(1.seconds()), Duration::MAX);
+    /// ```
+    pub const fn round_to(self, unit: Self) -> Self {
+        let unit = unit.whole_nanoseconds().abs();
+        if unit == 0 {
+            return self;
+        }
+
+        let nanoseconds = self.whole_nanoseconds();
+        let remainder = nanoseconds % unit;
+        let rounded = if remainder.abs() * 2 < unit {
+            nanoseconds - remainder
+        } else if remainder < 0 {
+            nanoseconds - remainder - unit
+        } else {
+            nanoseconds - remainder + unit
+        };
+
+        if rounded > Self::MAX.whole_nanoseconds() {
+            Self::MAX
+        } else if rounded < Self::MIN.whole_nanoseconds() {
+            Self::MIN
+        } else {
+            Self::nanoseconds_i128(rounded)
+        }
+    }
+
+    /// Truncate the duration to a multiple of `unit`, rounding towards zero. The sign of `unit` is
+    /// ignored, and a zero `unit` returns the duration unchanged.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(1_999.milliseconds().truncate_to(1.seconds()), 1.seconds());
+    /// assert_eq!((-1_999).milliseconds().truncate_to(1.seconds()), (-1).seconds());
+    /// assert_eq!(119.seconds().truncate_to(1.minutes()), 1.minutes());
+    /// assert_eq!(1.5.seconds().truncate_to(Duration::ZERO), 1.5.seconds());
+    /// ```
+    pub const fn truncate_to(self, unit: Self) -> Self {
+        let unit = unit.whole_nanoseconds().abs();
+        if unit == 0 {
+            return self;
+        }
+
+        let nanoseconds = self.whole_nanoseconds();
+        Self::nanoseconds_i128(nanoseconds - nanoseconds % unit)
+    }
+    // endregion rounding
+
+    // region: clamping
+    /// Restrict the duration to the range `min..=max`. This behaves like [`Ord::clamp`], but only
+    /// checks that `min <= max` when debug assertions are enabled.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(5.seconds().clamp(1.seconds(), 10.seconds()), 5.seconds());
+    /// assert_eq!(0.seconds().clamp(1.seconds(), 10.seconds()), 1.seconds());
+    /// assert_eq!(11.seconds().clamp(1.seconds(), 10.seconds()), 10.seconds());
+    /// assert_eq!((-5).seconds().clamp((-3).seconds(), 3.seconds()), (-3).seconds());
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        debug_assert!(min <= max, "`min` must not be greater than `max`");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Return the duration if it is positive, or [`Duration::ZERO`] otherwise.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(5.seconds().clamp_positive(), 5.seconds());
+    /// assert_eq!((-5).seconds().clamp_positive(), Duration::ZERO);
+    /// assert_eq!((-1).nanoseconds().clamp_positive(), Duration::ZERO);
+    /// ```
+    pub const fn clamp_positive(self) -> Self {
+        if self.is_negative() {
+            Self::ZERO
+        } else {
+            self
+        }
+    }
+    // endregion clamping
+
+    /// Computes the ratio of `self` to `rhs`. Both values are converted to floating point from
+    /// their total number of nanoseconds. This conversion is exact for durations of up to
+    /// 2<sup>53</sup> nanoseconds (about 104 days); longer durations are rounded to the nearest
+    /// representable `f64` before the division.
+    ///
+    /// Division by a zero duration follows IEEE 754 semantics: the result is infinite with the
+    /// sign of `self`, or NaN if `self` is also zero.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(30.seconds().div_duration(2.minutes()), 0.25);
+    /// assert_eq!((-3).seconds().div_duration(2.seconds()), -1.5);
+    /// assert_eq!(1.seconds().div_duration(0.seconds()), f64::INFINITY);
+    /// assert_eq!((-1).seconds().div_duration(0.seconds()), f64::NEG_INFINITY);
+    /// assert!(0.seconds().div_duration(0.seconds()).is_nan());
+    /// ```
+    pub fn div_duration(self, rhs: Self) -> f64 {
+        self.whole_nanoseconds() as f64 / rhs.whole_nanoseconds() as f64
+    }
+
     /// Runs a closure, returning the duration of time it took to run. The return value of the
     /// closure is provided in the second part of the tuple.
     #[cfg(feature = "std")]
@@ -737,8 +1673,271 @@ impl Duration {
 
         (end - start, return_value)
     }
+
+    // region: statistics
+    /// Computes the arithmetic mean of the provided durations, returning `None` if the slice is
+    /// empty. The result is truncated towards zero to the nearest nanosecond.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::mean(&[]), None);
+    /// assert_eq!(
+    ///     Duration::mean(&[1.seconds(), 2.seconds(), 4.seconds()]),
+    ///     Some(Duration::nanoseconds(2_333_333_333))
+    /// );
+    /// assert_eq!(
+    ///     Duration::mean(&[Duration::MAX, Duration::MAX]),
+    ///     Some(Duration::MAX)
+    /// );
+    /// ```
+    pub fn mean(durations: &[Self]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+
+        let sum = durations
+            .iter()
+            .map(|duration| duration.whole_nanoseconds())
+            .sum::<i128>();
+        Some(Self::nanoseconds_i128(sum / durations.len() as i128))
+    }
+
+    /// Computes the median of the provided durations, returning `None` if the slice is empty. If
+    /// there are an even number of durations, the mean of the middle two is returned.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::median(&[]), None);
+    /// assert_eq!(
+    ///     Duration::median(&[4.seconds(), 1.seconds(), 2.seconds()]),
+    ///     Some(2.seconds())
+    /// );
+    /// assert_eq!(
+    ///     Duration::median(&[4.seconds(), 1.seconds(), 2.seconds(), 100.seconds()]),
+    ///     Some(3.seconds())
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn median(durations: &[Self]) -> Option<Self> {
+        let mut sorted = durations.to_vec();
+        sorted.sort_unstable();
+
+        let middle = sorted.len() / 2;
+        match sorted.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(sorted[middle]),
+            _ => Self::mean(&sorted[middle - 1..=middle]),
+        }
+    }
+    // endregion statistics
 }
 
+// region: ISO 8601
+#[cfg(feature = "formatting")]
+impl Duration {
+    /// Format the `Duration` using the ISO 8601 duration syntax, such as `PT1H30M` or `P3DT4H`.
+    ///
+    /// Days are always exactly 24 hours, and years, months, and weeks are never emitted. Any
+    /// subsecond value is represented as a fractional number of seconds, without trailing zeros.
+    /// Negative durations have a leading `-`. A zero duration is formatted as `PT0S`.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(90.minutes().format_iso8601(), "PT1H30M");
+    /// assert_eq!((3.days() + 4.hours()).format_iso8601(), "P3DT4H");
+    /// assert_eq!(0.5.seconds().format_iso8601(), "PT0.5S");
+    /// assert_eq!((-1).milliseconds().format_iso8601(), "-PT0.001S");
+    /// assert_eq!(0.seconds().format_iso8601(), "PT0S");
+    /// ```
+    pub fn format_iso8601(self) -> String {
+        let seconds = self.seconds.unsigned_abs();
+        let nanoseconds = self.nanoseconds.unsigned_abs();
+        let days = seconds / 86_400;
+        let hours = seconds / 3_600 % 24;
+        let minutes = seconds / 60 % 60;
+        let seconds = seconds % 60;
+
+        let mut output = String::new();
+        if self.is_negative() {
+            output.push('-');
+        }
+        output.push('P');
+
+        if days != 0 {
+            output.push_str(&days.to_string());
+            output.push('D');
+        }
+        if days == 0 || hours != 0 || minutes != 0 || seconds != 0 || nanoseconds != 0 {
+            output.push('T');
+            if hours != 0 {
+                output.push_str(&hours.to_string());
+                output.push('H');
+            }
+            if minutes != 0 {
+                output.push_str(&minutes.to_string());
+                output.push('M');
+            }
+            if seconds != 0 || nanoseconds != 0 || (hours == 0 && minutes == 0) {
+                output.push_str(&seconds.to_string());
+                if nanoseconds != 0 {
+                    output.push('.');
+                    output.push_str(format!("{:09}", nanoseconds).trim_end_matches('0'));
+                }
+                output.push('S');
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Duration {
+    /// Parse a `Duration` from the ISO 8601 duration syntax, such as `PT1H30M` or `P3DT4H`. This
+    /// is the inverse of [`Duration::format_iso8601`].
+    ///
+    /// Weeks, days, hours, minutes, and seconds are accepted, in that order. Days are always
+    /// exactly 24 hours. As years and months do not have a fixed length, they are rejected. The
+    /// seconds may have up to nine fractional digits, separated by either `.` or `,`. A leading
+    /// `-` negates the entire duration.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::parse_iso8601("PT1H30M")?, 90.minutes());
+    /// assert_eq!(Duration::parse_iso8601("P3DT4H")?, 3.days() + 4.hours());
+    /// assert_eq!(Duration::parse_iso8601("P2W")?, 2.weeks());
+    /// assert_eq!(Duration::parse_iso8601("PT0.5S")?, 0.5.seconds());
+    /// assert_eq!(Duration::parse_iso8601("-PT0,001S")?, (-1).milliseconds());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert!(Duration::parse_iso8601("P").is_err()); // No components.
+    /// assert!(Duration::parse_iso8601("P1DT").is_err()); // No time components.
+    /// assert!(Duration::parse_iso8601("P1M").is_err()); // Months have no fixed length.
+    /// assert!(Duration::parse_iso8601("PT1S1M").is_err()); // Components out of order.
+    /// assert!(Duration::parse_iso8601("PT1.5M").is_err()); // Only seconds may be fractional.
+    /// ```
+    pub fn parse_iso8601(input: &str) -> Result<Self, error::Parse> {
+        use error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+
+        let input_len = input.len();
+        let mut input = input.as_bytes();
+        let is_negative = match input {
+            [b'-', rest @ ..] => {
+                input = rest;
+                true
+            }
+            _ => false,
+        };
+        input = match input {
+            [b'P', rest @ ..] => rest,
+            _ => return Err(error::Parse::at(InvalidLiteral, input_len, input)),
+        };
+
+        // The magnitude of a negative duration may be one second larger than that of a positive one.
+        let max_seconds = i64::MAX as u64 + is_negative as u64;
+        let mut seconds = 0_u64;
+        let mut nanoseconds = 0_i32;
+        // The position of the most recently parsed designator in `WWDDHHMMSS`, if any.
+        let mut last_position = None;
+        let mut in_time = false;
+        let mut time_is_empty = false;
+
+        while !input.is_empty() {
+            if !in_time && input[0] == b'T' {
+                in_time = true;
+                time_is_empty = true;
+                input = &input[1..];
+                continue;
+            }
+
+            let digits = input.iter().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 {
+                return Err(if last_position.is_some() {
+                    error::Parse::UnexpectedTrailingCharacters {
+                        position: input_len - input.len(),
+                    }
+                } else {
+                    error::Parse::at(InvalidLiteral, input_len, input)
+                });
+            }
+            let value_input = input;
+            let (value, rest) = input.split_at(digits);
+            input = rest;
+
+            let fraction_input = input;
+            let mut fraction: &[u8] = &[];
+            if let [b'.' | b',', rest @ ..] = input {
+                let digits = rest.iter().take_while(|c| c.is_ascii_digit()).count();
+                if digits == 0 {
+                    return Err(error::Parse::at(InvalidLiteral, input_len, rest));
+                }
+                let (value, rest) = rest.split_at(digits);
+                fraction = value;
+                input = rest;
+            }
+
+            let (position, name, unit) = match (in_time, input.first()) {
+                (false, Some(b'W')) => (0, "weeks", 604_800),
+                (false, Some(b'D')) => (1, "days", 86_400),
+                (true, Some(b'H')) => (2, "hours", 3_600),
+                (true, Some(b'M')) => (3, "minutes", 60),
+                (true, Some(b'S')) => (4, "seconds", 1),
+                _ => return Err(error::Parse::at(InvalidLiteral, input_len, input)),
+            };
+            if last_position >= Some(position) {
+                return Err(error::Parse::at(InvalidLiteral, input_len, input));
+            }
+            if !fraction.is_empty() && unit != 1 {
+                return Err(error::Parse::at(InvalidLiteral, input_len, fraction_input));
+            }
+            input = &input[1..];
+            last_position = Some(position);
+            time_is_empty = false;
+
+            seconds = core::str::from_utf8(value)
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .and_then(|value| value.checked_mul(unit))
+                .and_then(|value| value.checked_add(seconds))
+                .filter(|&value| value <= max_seconds)
+                .ok_or_else(|| error::Parse::at(InvalidComponent(name), input_len, value_input))?;
+
+            if !fraction.is_empty() {
+                if fraction.len() > 9 {
+                    // The first digit that cannot be represented follows the separator and nine
+                    // digits.
+                    return Err(error::Parse::at(
+                        InvalidComponent(name),
+                        input_len,
+                        &fraction_input[10..],
+                    ));
+                }
+                nanoseconds = fraction
+                    .iter()
+                    .chain(core::iter::repeat(&b'0'))
+                    .take(9)
+                    .fold(0, |value, digit| value * 10 + (digit - b'0') as i32);
+            }
+        }
+
+        // All input has been consumed at this point, so this is always an unexpected end.
+        if last_position.is_none() || time_is_empty {
+            return Err(error::Parse::at(InvalidLiteral, input_len, input));
+        }
+
+        Ok(if is_negative {
+            Self::new_unchecked((seconds as i64).wrapping_neg(), -nanoseconds)
+        } else {
+            Self::new_unchecked(seconds as i64, nanoseconds)
+        })
+    }
+}
+// endregion ISO 8601
+
 // region: trait impls
 impl TryFrom<StdDuration> for Duration {
     type Error = error::ConversionRange;
@@ -754,6 +1953,32 @@ impl TryFrom<StdDuration> for Duration {
     }
 }
 
+/// Convert a [`Duration`] to a [`std::time::Duration`], failing if the value is negative. The sign
+/// is never discarded; to convert the magnitude of a possibly negative duration, call
+/// [`Duration::abs`] first.
+///
+/// ```rust
+/// # use core::convert::TryFrom;
+/// # use time::{Duration, error::ConversionRange};
+/// # use std::time::Duration as StdDuration;
+/// assert_eq!(
+///     StdDuration::try_from(Duration::seconds(1)),
+///     Ok(StdDuration::from_secs(1))
+/// );
+/// assert_eq!(StdDuration::try_from(Duration::ZERO), Ok(StdDuration::ZERO));
+/// assert_eq!(
+///     StdDuration::try_from(Duration::seconds(-1)),
+///     Err(ConversionRange)
+/// );
+/// assert_eq!(
+///     StdDuration::try_from(Duration::nanoseconds(-1)),
+///     Err(ConversionRange)
+/// );
+/// assert_eq!(
+///     StdDuration::try_from(Duration::seconds(-1).abs()),
+///     Ok(StdDuration::from_secs(1))
+/// );
+/// ```
 impl TryFrom<Duration> for StdDuration {
     type Error = error::ConversionRange;
 
@@ -803,7 +2028,7 @@ impl Neg for Duration {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Self::new_unchecked(-self.seconds, -self.nanoseconds)
+        self.checked_neg().expect("overflow when negating duration")
     }
 }
 
@@ -988,6 +2213,20 @@ impl PartialOrd<Duration> for StdDuration {
     }
 }
 
+/// Sum the durations of an iterator. An empty iterator sums to [`Duration::ZERO`].
+///
+/// # Panics
+///
+/// This panics if the sum overflows, as with [`Add`]. Use [`Duration::checked_add`] or
+/// [`Duration::saturating_add`] with [`Iterator::try_fold`] or [`Iterator::fold`] to handle
+/// overflow explicitly.
+///
+/// ```rust
+/// # use time::{Duration, ext::NumericalDuration};
+/// assert_eq!(Vec::<Duration>::new().into_iter().sum::<Duration>(), Duration::ZERO);
+/// assert_eq!([1.seconds(), 500.milliseconds()].iter().sum::<Duration>(), 1.5.seconds());
+/// assert_eq!((0..10_000).map(|_| 1.days()).sum::<Duration>(), 10_000.days());
+/// ```
 impl Sum for Duration {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.reduce(|a, b| a + b).unwrap_or_default()
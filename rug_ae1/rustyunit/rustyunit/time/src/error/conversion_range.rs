@@ -7,6 +7,20 @@ use crate::error;
 
 /// An error type indicating that a conversion failed because the target type could not store the
 /// initial value.
+///
+/// ```rust
+/// # use time::{error, Duration};
+/// let err = Duration::try_seconds_f64(f64::NAN).unwrap_err();
+/// assert_eq!(err, error::ConversionRange);
+/// assert_eq!(
+///     err.to_string(),
+///     "Source value is out of range for the target type"
+/// );
+/// assert!(matches!(
+///     time::Error::from(err),
+///     time::Error::ConversionRange(_)
+/// ));
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ConversionRange;
 
@@ -2,6 +2,7 @@
 
 use core::convert::TryFrom;
 use core::fmt;
+#[cfg(feature = "std")]
 use std::io;
 
 use crate::error;
@@ -20,7 +21,11 @@ pub enum Format {
     /// This variant is only returned when using well-known formats.
     InvalidComponent(&'static str),
     /// A value of `std::io::Error` was returned internally.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     StdIo(io::Error),
+    /// A value of `core::fmt::Error` was returned internally.
+    Fmt(fmt::Error),
 }
 
 impl fmt::Display for Format {
@@ -35,17 +40,23 @@ impl fmt::Display for Format {
                 "The {} component cannot be formatted into the requested format.",
                 component
             ),
+            #[cfg(feature = "std")]
             Self::StdIo(err) => err.fmt(f),
+            Self::Fmt(err) => err.fmt(f),
         }
     }
 }
 
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
 impl From<io::Error> for Format {
     fn from(err: io::Error) -> Self {
         Self::StdIo(err)
     }
 }
 
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
 impl TryFrom<Format> for io::Error {
     type Error = error::DifferentVariant;
 
@@ -63,6 +74,24 @@ impl std::error::Error for Format {
         match *self {
             Self::InsufficientTypeInformation | Self::InvalidComponent(_) => None,
             Self::StdIo(ref err) => Some(err),
+            Self::Fmt(ref err) => Some(err),
+        }
+    }
+}
+
+impl From<fmt::Error> for Format {
+    fn from(err: fmt::Error) -> Self {
+        Self::Fmt(err)
+    }
+}
+
+impl TryFrom<Format> for fmt::Error {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: Format) -> Result<Self, Self::Error> {
+        match err {
+            Format::Fmt(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
         }
     }
 }
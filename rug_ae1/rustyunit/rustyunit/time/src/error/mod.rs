@@ -9,6 +9,7 @@ pub mod format;
 pub mod indeterminate_offset;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 pub mod invalid_format_description;
+pub mod invalid_variant;
 #[cfg(feature = "parsing")]
 pub mod parse;
 #[cfg(feature = "parsing")]
@@ -27,6 +28,7 @@ pub use format::Format;
 pub use indeterminate_offset::IndeterminateOffset;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 pub use invalid_format_description::InvalidFormatDescription;
+pub use invalid_variant::InvalidVariant;
 #[cfg(feature = "parsing")]
 pub use parse::Parse;
 #[cfg(feature = "parsing")]
@@ -53,12 +55,15 @@ pub enum Error {
     ParseFromDescription(ParseFromDescription),
     #[cfg(feature = "parsing")]
     #[non_exhaustive]
-    UnexpectedTrailingCharacters,
+    UnexpectedTrailingCharacters {
+        position: usize,
+    },
     #[cfg(feature = "parsing")]
     TryFromParsed(TryFromParsed),
     #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
     InvalidFormatDescription(InvalidFormatDescription),
     DifferentVariant(DifferentVariant),
+    InvalidVariant(InvalidVariant),
 }
 
 impl fmt::Display for Error {
@@ -73,12 +78,15 @@ impl fmt::Display for Error {
             #[cfg(feature = "parsing")]
             Self::ParseFromDescription(e) => e.fmt(f),
             #[cfg(feature = "parsing")]
-            Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
+            Self::UnexpectedTrailingCharacters { .. } => {
+                f.write_str("unexpected trailing characters")
+            }
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(e) => e.fmt(f),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(e) => e.fmt(f),
             Self::DifferentVariant(e) => e.fmt(f),
+            Self::InvalidVariant(e) => e.fmt(f),
         }
     }
 }
@@ -96,12 +104,13 @@ impl std::error::Error for Error {
             #[cfg(feature = "parsing")]
             Self::ParseFromDescription(err) => Some(err),
             #[cfg(feature = "parsing")]
-            Self::UnexpectedTrailingCharacters => None,
+            Self::UnexpectedTrailingCharacters { .. } => None,
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(err) => Some(err),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(err) => Some(err),
             Self::DifferentVariant(err) => Some(err),
+            Self::InvalidVariant(err) => Some(err),
         }
     }
 }
@@ -13,19 +13,131 @@ use crate::error::{self, ParseFromDescription, TryFromParsed};
 pub enum Parse {
     #[allow(clippy::missing_docs_in_private_items)]
     TryFromParsed(TryFromParsed),
-    #[allow(clippy::missing_docs_in_private_items)]
-    ParseFromDescription(ParseFromDescription),
+    /// A literal or component of the input could not be parsed.
+    #[non_exhaustive]
+    ParseFromDescription {
+        /// The literal or component that could not be parsed.
+        error: ParseFromDescription,
+        /// The byte offset of the first byte that could not be parsed, if it is known. This is the
+        /// length of the input if it ended before the literal or component.
+        position: Option<usize>,
+        /// Whether the input ended before the literal or component.
+        at_end: bool,
+    },
     /// The input should have ended, but there were characters remaining.
     #[non_exhaustive]
-    UnexpectedTrailingCharacters,
+    UnexpectedTrailingCharacters {
+        /// The byte offset of the first character that was not consumed.
+        position: usize,
+    },
+}
+
+impl Parse {
+    /// Obtain the byte offset into the input at which parsing failed, if it is known.
+    ///
+    /// For a literal or component that could not be parsed, this is the index of the first byte
+    /// that could not be parsed, or the length of the input if it ended early. For unexpected
+    /// trailing characters, it is the index of the first byte that was not consumed.
+    ///
+    /// `None` is returned for a [`Parse::TryFromParsed`] error, which is only detected once the
+    /// parsed values are combined. It is also returned for a [`Parse::ParseFromDescription`] error
+    /// created from a [`ParseFromDescription`] without a position.
+    ///
+    /// ```rust
+    /// # use time::{Date, format_description};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let err = Date::parse("2021-03-04 extra", &format).unwrap_err();
+    /// assert_eq!(err.position(), Some(10));
+    /// let err = Date::parse("2021-03-", &format).unwrap_err();
+    /// assert_eq!(err.position(), Some(8));
+    /// let err = Date::parse("2021-xx-04", &format).unwrap_err();
+    /// assert_eq!(err.position(), Some(5));
+    /// assert_eq!(err.to_string(), "the 'month' component could not be parsed");
+    /// let err = Date::parse("2021-02-30", &format).unwrap_err();
+    /// assert_eq!(err.position(), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// The same applies to the well-known formats and to ISO 8601 durations.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+    /// let err = OffsetDateTime::parse("2021-03-04T05:06:07Z extra", &Rfc3339).unwrap_err();
+    /// assert_eq!(err.position(), Some(20));
+    /// let err = OffsetDateTime::parse("2021-03-04T05:06:07+01:", &Rfc3339).unwrap_err();
+    /// assert_eq!(err.position(), Some(23));
+    /// assert!(err.is_unexpected_end());
+    /// let err = OffsetDateTime::parse("2021-03-04T05:06:07+0100", &Rfc3339).unwrap_err();
+    /// assert_eq!(err.position(), Some(22));
+    ///
+    /// assert_eq!(Duration::parse_iso8601("PT1H30Mx").unwrap_err().position(), Some(7));
+    /// assert_eq!(Duration::parse_iso8601("PT1H30").unwrap_err().position(), Some(6));
+    /// assert_eq!(Duration::parse_iso8601("P1DT").unwrap_err().position(), Some(4));
+    /// assert_eq!(Duration::parse_iso8601("PT1.5M").unwrap_err().position(), Some(3));
+    /// ```
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            Self::ParseFromDescription { position, .. } => *position,
+            Self::UnexpectedTrailingCharacters { position } => Some(*position),
+            Self::TryFromParsed(_) => None,
+        }
+    }
+
+    /// Check if parsing failed because the input ended before everything that was expected was
+    /// present.
+    ///
+    /// This is the case when no input remains at the point a literal or component is expected. The
+    /// error is still reported as the literal or component that is missing. A component that is
+    /// cut short, such as a two-digit day with only one digit present, is not an unexpected end.
+    ///
+    /// ```rust
+    /// # use time::{error, format_description, Date, Time};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// assert!(Date::parse("2021-03", &format).unwrap_err().is_unexpected_end());
+    /// assert!(Date::parse("", &format).unwrap_err().is_unexpected_end());
+    /// assert!(!Date::parse("2021-03-0", &format).unwrap_err().is_unexpected_end());
+    /// assert!(!Date::parse("2021-03-04 extra", &format).unwrap_err().is_unexpected_end());
+    ///
+    /// let format = format_description::parse("[hour]:[minute]")?;
+    /// let err = Time::parse("12:", &format).unwrap_err();
+    /// assert!(err.is_unexpected_end());
+    /// assert_eq!(err.position(), Some(3));
+    /// assert!(matches!(
+    ///     err,
+    ///     error::Parse::ParseFromDescription {
+    ///         error: error::ParseFromDescription::InvalidComponent("minute"),
+    ///         ..
+    ///     }
+    /// ));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn is_unexpected_end(&self) -> bool {
+        matches!(self, Self::ParseFromDescription { at_end: true, .. })
+    }
+
+    /// Create an error for `error`, which occurred with `remaining` bytes of an input of length
+    /// `input_len` left.
+    pub(crate) const fn at(
+        error: ParseFromDescription,
+        input_len: usize,
+        remaining: &[u8],
+    ) -> Self {
+        Self::ParseFromDescription {
+            error,
+            position: Some(input_len - remaining.len()),
+            at_end: remaining.is_empty(),
+        }
+    }
 }
 
 impl fmt::Display for Parse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::TryFromParsed(err) => err.fmt(f),
-            Self::ParseFromDescription(err) => err.fmt(f),
-            Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
+            Self::ParseFromDescription { error, .. } => error.fmt(f),
+            Self::UnexpectedTrailingCharacters { .. } => {
+                f.write_str("unexpected trailing characters")
+            }
         }
     }
 }
@@ -35,8 +147,8 @@ impl std::error::Error for Parse {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::TryFromParsed(err) => Some(err),
-            Self::ParseFromDescription(err) => Some(err),
-            Self::UnexpectedTrailingCharacters => None,
+            Self::ParseFromDescription { error, .. } => Some(error),
+            Self::UnexpectedTrailingCharacters { .. } => None,
         }
     }
 }
@@ -62,8 +174,12 @@ impl TryFrom<Parse> for TryFromParsed {
 
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
 impl From<ParseFromDescription> for Parse {
-    fn from(err: ParseFromDescription) -> Self {
-        Self::ParseFromDescription(err)
+    fn from(error: ParseFromDescription) -> Self {
+        Self::ParseFromDescription {
+            error,
+            position: None,
+            at_end: false,
+        }
     }
 }
 
@@ -73,7 +189,7 @@ impl TryFrom<Parse> for ParseFromDescription {
 
     fn try_from(err: Parse) -> Result<Self, Self::Error> {
         match err {
-            Parse::ParseFromDescription(err) => Ok(err),
+            Parse::ParseFromDescription { error, .. } => Ok(error),
             _ => Err(error::DifferentVariant),
         }
     }
@@ -84,8 +200,10 @@ impl From<Parse> for crate::Error {
     fn from(err: Parse) -> Self {
         match err {
             Parse::TryFromParsed(err) => Self::TryFromParsed(err),
-            Parse::ParseFromDescription(err) => Self::ParseFromDescription(err),
-            Parse::UnexpectedTrailingCharacters => Self::UnexpectedTrailingCharacters,
+            Parse::ParseFromDescription { error, .. } => Self::ParseFromDescription(error),
+            Parse::UnexpectedTrailingCharacters { position } => {
+                Self::UnexpectedTrailingCharacters { position }
+            }
         }
     }
 }
@@ -96,8 +214,10 @@ impl TryFrom<crate::Error> for Parse {
 
     fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
         match err {
-            crate::Error::ParseFromDescription(err) => Ok(Self::ParseFromDescription(err)),
-            crate::Error::UnexpectedTrailingCharacters => Ok(Self::UnexpectedTrailingCharacters),
+            crate::Error::ParseFromDescription(err) => Ok(err.into()),
+            crate::Error::UnexpectedTrailingCharacters { position } => {
+                Ok(Self::UnexpectedTrailingCharacters { position })
+            }
             crate::Error::TryFromParsed(err) => Ok(Self::TryFromParsed(err)),
             _ => Err(error::DifferentVariant),
         }
@@ -18,7 +18,7 @@ use core::fmt;
 
 pub use self::component::Component;
 #[cfg(feature = "alloc")]
-pub use self::parse::parse;
+pub use self::parse::{escape_literal, parse};
 use crate::error;
 
 /// Helper methods.
@@ -76,6 +76,22 @@ pub mod well_known {
     /// # Ok::<_, time::Error>(())
     /// ```
     ///
+    /// The obsolete syntax is accepted when parsing. Two-digit years before 50 are in the 2000s,
+    /// and the rest are in the 1900s. As the RFC permits, military zones are treated as UTC.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Rfc2822, macros::datetime, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse("Tue, 1 Jul 03 10:52:37 +0200", &Rfc2822)?,
+    ///     datetime!(2003-07-01 10:52:37 +02:00)
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse("Sat, 12 Jun 93 13:25:19 A", &Rfc2822)?,
+    ///     datetime!(1993-06-12 13:25:19 +00:00)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
     /// ```rust
     /// # use time::{format_description::well_known::Rfc2822, macros::datetime};
     /// assert_eq!(
@@ -88,6 +104,30 @@ pub mod well_known {
     pub struct Rfc2822;
 }
 
+/// The ISO 8601 week date format, `YYYY-Www-D`, where the year is the ISO week-based year.
+///
+/// The sign of the year is not mandatory, but it is still emitted and required for years with more
+/// than four digits when the `large-dates` feature is enabled, so large years round-trip.
+pub(crate) const ISO_WEEK_DATE: &[FormatItem<'static>] = &[
+    FormatItem::Component(Component::Year(modifier::Year {
+        padding: modifier::Padding::Zero,
+        repr: modifier::YearRepr::Full,
+        iso_week_based: true,
+        sign_is_mandatory: false,
+    })),
+    FormatItem::Literal(b"-W"),
+    FormatItem::Component(Component::WeekNumber(modifier::WeekNumber {
+        padding: modifier::Padding::Zero,
+        repr: modifier::WeekNumberRepr::Iso,
+    })),
+    FormatItem::Literal(b"-"),
+    FormatItem::Component(Component::Weekday(modifier::Weekday {
+        repr: modifier::WeekdayRepr::Monday,
+        one_indexed: true,
+        case_sensitive: true,
+    })),
+];
+
 /// A complete description of how to format and parse a type.
 #[non_exhaustive]
 #[cfg_attr(not(feature = "alloc"), derive(Debug))]
@@ -106,7 +146,37 @@ pub enum FormatItem<'a> {
     /// A `FormatItem` that may or may not be present when parsing. If parsing fails, there will be
     /// no effect on the resulting `struct`.
     ///
-    /// This variant has no effect on formatting, as the value is guaranteed to be present.
+    /// When formatting, the item is omitted if the value being formatted does not contain the
+    /// information the item requires. This allows a single description with an optional offset to
+    /// be used for both [`OffsetDateTime`](crate::OffsetDateTime) and
+    /// [`PrimitiveDateTime`](crate::PrimitiveDateTime). Any other error is returned as usual.
+    ///
+    /// ```rust
+    /// # use time::{
+    /// #     format_description::{modifier, Component, FormatItem},
+    /// #     macros::{datetime, format_description},
+    /// # };
+    /// const OFFSET: FormatItem<'_> = FormatItem::Compound(&[
+    ///     FormatItem::Literal(b" "),
+    ///     FormatItem::Component(Component::OffsetHour(modifier::OffsetHour::default())),
+    ///     FormatItem::Literal(b":"),
+    ///     FormatItem::Component(Component::OffsetMinute(modifier::OffsetMinute::default())),
+    /// ]);
+    /// const FORMAT: &[FormatItem<'_>] = &[
+    ///     FormatItem::Compound(format_description!("[year]-[month]-[day] [hour]:[minute]")),
+    ///     FormatItem::Optional(&OFFSET),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     datetime!(2021-01-02 03:04 +05:06).format(FORMAT)?,
+    ///     "2021-01-02 03:04 +05:06"
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-01-02 03:04).format(FORMAT)?,
+    ///     "2021-01-02 03:04"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
     Optional(&'a Self),
     /// A series of `FormatItem`s where, when parsing, the first successful parse is used. When
     /// formatting, the first element of the slice is used.  An empty slice is a no-op when
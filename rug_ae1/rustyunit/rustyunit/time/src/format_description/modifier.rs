@@ -42,6 +42,22 @@ pub struct Month {
 }
 
 /// Ordinal day of the year.
+///
+/// This is written as `[ordinal]` in a format description, and is equivalent to `%j` in
+/// `strftime`. When parsing, it is combined with the year to obtain the date.
+///
+/// ```rust
+/// # use time::{format_description, macros::date, Date};
+/// let format = format_description::parse("[year]-[ordinal]")?;
+/// assert_eq!(date!(2024 - 12 - 31).format(&format)?, "2024-366");
+/// assert_eq!(Date::parse("2024-366", &format)?, date!(2024 - 12 - 31));
+/// assert_eq!(Date::parse("2024-060", &format)?, date!(2024 - 02 - 29));
+/// assert!(Date::parse("2023-366", &format).is_err());
+///
+/// let unpadded = format_description::parse("[year]-[ordinal padding:none]")?;
+/// assert_eq!(date!(2024 - 01 - 05).format(&unpadded)?, "2024-5");
+/// # Ok::<_, time::Error>(())
+/// ```
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Ordinal {
@@ -153,7 +169,26 @@ pub struct Period {
     pub is_uppercase: bool,
     /// Is the value case sensitive when parsing?
     ///
-    /// Note that when `false`, the `is_uppercase` field has no effect on parsing behavior.
+    /// Note that when `false`, the `is_uppercase` field has no effect on parsing behavior. The
+    /// dotted forms `a.m.` and `p.m.` are also accepted, in any case.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time, Time};
+    /// let format = format_description::parse(
+    ///     "[hour repr:12]:[minute] [period case_sensitive:false]",
+    /// )?;
+    /// assert_eq!(Time::parse("12:30 am", &format)?, time!(0:30));
+    /// assert_eq!(Time::parse("12:30 A.M.", &format)?, time!(0:30));
+    /// assert_eq!(Time::parse("12:30 PM", &format)?, time!(12:30));
+    /// assert_eq!(Time::parse("12:30 p.M.", &format)?, time!(12:30));
+    /// assert_eq!(Time::parse("01:30 pm", &format)?, time!(13:30));
+    ///
+    /// let strict = format_description::parse("[hour repr:12]:[minute] [period]")?;
+    /// assert!(Time::parse("12:30 am", &strict).is_err());
+    /// assert!(Time::parse("12:30 A.M.", &strict).is_err());
+    /// assert_eq!(Time::parse("12:30 AM", &strict)?, time!(0:30));
+    /// # Ok::<_, time::Error>(())
+    /// ```
     pub case_sensitive: bool,
 }
 
@@ -189,6 +224,33 @@ pub enum SubsecondDigits {
     Nine,
     /// Any number of digits (up to nine) that is at least one. When formatting, the minimum digits
     /// necessary will be used.
+    ///
+    /// When parsing, any number of digits is accepted. Digits beyond the ninth are truncated rather
+    /// than rounded, as rounding could require carrying into the second, and the same is done
+    /// when parsing [`Rfc3339`](crate::format_description::well_known::Rfc3339).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time, Time};
+    /// let format = format_description::parse("[hour]:[minute]:[second].[subsecond]")?;
+    /// assert_eq!(Time::parse("12:00:00.1", &format)?, time!(12:00:00.1));
+    /// assert_eq!(
+    ///     Time::parse("12:00:00.123456", &format)?,
+    ///     time!(12:00:00.123_456)
+    /// );
+    /// assert_eq!(
+    ///     Time::parse("12:00:00.1234567891", &format)?,
+    ///     time!(12:00:00.123_456_789)
+    /// );
+    /// assert_eq!(
+    ///     Time::parse("12:00:00.1234567896", &format)?,
+    ///     time!(12:00:00.123_456_789)
+    /// );
+    /// assert_eq!(
+    ///     Time::parse("12:59:59.9999999999", &format)?,
+    ///     time!(12:59:59.999_999_999)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
     OneOrMore,
 }
 
@@ -1,5 +1,6 @@
 //! Parse a format description into a standardized representation.
 
+use alloc::string::String;
 use alloc::vec::Vec;
 
 use crate::error::InvalidFormatDescription;
@@ -95,3 +96,24 @@ pub fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
 
     Ok(compound)
 }
+
+/// Escape a string so that it is treated as a literal when embedded in a format description.
+///
+/// The only special character outside of a component is `[`, which begins a component. It is
+/// escaped by doubling it, as `[[`. All other characters, including `]`, are already literal and
+/// are left unchanged.
+///
+/// ```rust
+/// # use time::{format_description, macros::date};
+/// assert_eq!(format_description::escape_literal("[draft] notes"), "[[draft] notes");
+///
+/// let label = "[draft] ";
+/// let format = format_description::escape_literal(label) + "[year]-[month]-[day]";
+/// let format = format_description::parse(&format)?;
+/// assert_eq!(date!(2021 - 01 - 02).format(&format)?, "[draft] 2021-01-02");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn escape_literal(s: &str) -> String {
+    s.replace('[', "[[")
+}
@@ -1,12 +1,12 @@
 //! A trait that can be used to format an item from its components.
 
+use alloc::string::String;
 use core::ops::Deref;
-use std::io;
 
 use crate::format_description::well_known::{Rfc2822, Rfc3339};
-use crate::format_description::FormatItem;
+use crate::format_description::{Component, FormatItem};
 use crate::formatting::{
-    format_component, format_number_pad_zero, write, MONTH_NAMES, WEEKDAY_NAMES,
+    format_component, format_number_pad_zero, write, FmtWriter, Output, MONTH_NAMES, WEEKDAY_NAMES,
 };
 use crate::{error, Date, Time, UtcOffset};
 
@@ -30,7 +30,7 @@ mod sealed {
         /// Format the item into the provided output, returning the number of bytes written.
         fn format_into(
             &self,
-            output: &mut impl io::Write,
+            output: &mut impl Output,
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
@@ -43,9 +43,9 @@ mod sealed {
             time: Option<Time>,
             offset: Option<UtcOffset>,
         ) -> Result<String, error::Format> {
-            let mut buf = Vec::new();
-            self.format_into(&mut buf, date, time, offset)?;
-            Ok(String::from_utf8_lossy(&buf).into_owned())
+            let mut buf = String::new();
+            self.format_into(&mut FmtWriter(&mut buf), date, time, offset)?;
+            Ok(buf)
         }
     }
 }
@@ -54,7 +54,7 @@ mod sealed {
 impl<'a> sealed::Sealed for FormatItem<'a> {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
@@ -63,7 +63,16 @@ impl<'a> sealed::Sealed for FormatItem<'a> {
             Self::Literal(literal) => write(output, literal)?,
             Self::Component(component) => format_component(output, component, date, time, offset)?,
             Self::Compound(items) => items.format_into(output, date, time, offset)?,
-            Self::Optional(item) => item.format_into(output, date, time, offset)?,
+            // An optional item is omitted when the type being formatted lacks the information it
+            // requires, such as the offset of a `PrimitiveDateTime`. This is checked before
+            // anything is written so that no partial output is produced.
+            Self::Optional(item) => {
+                if item.has_required_information(date.is_some(), time.is_some(), offset.is_some()) {
+                    item.format_into(output, date, time, offset)?
+                } else {
+                    0
+                }
+            }
             Self::First(items) => match items {
                 [] => 0,
                 [item, ..] => item.format_into(output, date, time, offset)?,
@@ -72,10 +81,31 @@ impl<'a> sealed::Sealed for FormatItem<'a> {
     }
 }
 
+impl FormatItem<'_> {
+    /// Whether the item can be formatted given which of the date, time, and offset are present.
+    fn has_required_information(&self, date: bool, time: bool, offset: bool) -> bool {
+        use Component::*;
+        match *self {
+            Self::Literal(_) | Self::Optional(_) => true,
+            Self::Component(component) => match component {
+                Day(_) | Month(_) | Ordinal(_) | Weekday(_) | WeekNumber(_) | Year(_) => date,
+                Hour(_) | Minute(_) | Period(_) | Second(_) | Subsecond(_) => time,
+                OffsetHour(_) | OffsetMinute(_) | OffsetSecond(_) => offset,
+            },
+            Self::Compound(items) => items
+                .iter()
+                .all(|item| item.has_required_information(date, time, offset)),
+            Self::First(items) => items.first().map_or(true, |item| {
+                item.has_required_information(date, time, offset)
+            }),
+        }
+    }
+}
+
 impl<'a> sealed::Sealed for [FormatItem<'a>] {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
@@ -94,7 +124,7 @@ where
 {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
@@ -108,7 +138,7 @@ where
 impl sealed::Sealed for Rfc2822 {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
@@ -157,7 +187,7 @@ impl sealed::Sealed for Rfc2822 {
 impl sealed::Sealed for Rfc3339 {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
@@ -1,10 +1,10 @@
 //! Formatting for various types.
 
 pub(crate) mod formattable;
-
-use std::io;
+mod output;
 
 pub use self::formattable::Formattable;
+pub(crate) use self::output::{FmtWriter, Output};
 use crate::format_description::{modifier, Component};
 use crate::{error, Date, Time, UtcOffset};
 
@@ -116,19 +116,19 @@ impl DigitCount for u32 {
 // endregion extension trait
 
 /// Write all bytes to the output, returning the number of bytes written.
-fn write(output: &mut impl io::Write, bytes: &[u8]) -> io::Result<usize> {
-    output.write_all(bytes)?;
+fn write(output: &mut impl Output, bytes: &[u8]) -> Result<usize, error::Format> {
+    output.write_bytes(bytes)?;
     Ok(bytes.len())
 }
 
 /// Format a number with the provided padding and width.
 ///
 /// The sign must be written by the caller.
-pub(crate) fn format_number<W: io::Write, V: itoa::Integer + DigitCount + Copy, const WIDTH: u8>(
+pub(crate) fn format_number<W: Output, V: itoa::Integer + DigitCount + Copy, const WIDTH: u8>(
     output: &mut W,
     value: V,
     padding: modifier::Padding,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match padding {
         modifier::Padding::Space => format_number_pad_space::<_, _, WIDTH>(output, value),
         modifier::Padding::Zero => format_number_pad_zero::<_, _, WIDTH>(output, value),
@@ -140,13 +140,13 @@ pub(crate) fn format_number<W: io::Write, V: itoa::Integer + DigitCount + Copy,
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number_pad_space<
-    W: io::Write,
+    W: Output,
     V: itoa::Integer + DigitCount + Copy,
     const WIDTH: u8,
 >(
     output: &mut W,
     value: V,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let mut bytes = 0;
     for _ in 0..(WIDTH.saturating_sub(value.num_digits())) {
         bytes += write(output, &[b' '])?;
@@ -159,13 +159,13 @@ pub(crate) fn format_number_pad_space<
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number_pad_zero<
-    W: io::Write,
+    W: Output,
     V: itoa::Integer + DigitCount + Copy,
     const WIDTH: u8,
 >(
     output: &mut W,
     value: V,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let mut bytes = 0;
     for _ in 0..(WIDTH.saturating_sub(value.num_digits())) {
         bytes += write(output, &[b'0'])?;
@@ -178,7 +178,7 @@ pub(crate) fn format_number_pad_zero<
 /// component requires information that it does not provide or if the value cannot be output to the
 /// stream.
 pub(crate) fn format_component(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     component: Component,
     date: Option<Date>,
     time: Option<Time>,
@@ -207,23 +207,23 @@ pub(crate) fn format_component(
 // region: date formatters
 /// Format the day into the designated output.
 fn fmt_day(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Day { padding }: modifier::Day,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<_, _, 2>(output, date.day(), padding)
 }
 
 /// Format the month into the designated output.
 fn fmt_month(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Month {
         padding,
         repr,
         case_sensitive: _, // no effect on formatting
     }: modifier::Month,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match repr {
         modifier::MonthRepr::Numerical => {
             format_number::<_, _, 2>(output, date.month() as u8, padding)
@@ -235,23 +235,23 @@ fn fmt_month(
 
 /// Format the ordinal into the designated output.
 fn fmt_ordinal(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Ordinal { padding }: modifier::Ordinal,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<_, _, 3>(output, date.ordinal(), padding)
 }
 
 /// Format the weekday into the designated output.
 fn fmt_weekday(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Weekday {
         repr,
         one_indexed,
         case_sensitive: _, // no effect on formatting
     }: modifier::Weekday,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match repr {
         modifier::WeekdayRepr::Short => write(
             output,
@@ -276,10 +276,10 @@ fn fmt_weekday(
 
 /// Format the week number into the designated output.
 fn fmt_week_number(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::WeekNumber { padding, repr }: modifier::WeekNumber,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<_, _, 2>(
         output,
         match repr {
@@ -293,7 +293,7 @@ fn fmt_week_number(
 
 /// Format the year into the designated output.
 fn fmt_year(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Year {
         padding,
@@ -301,7 +301,7 @@ fn fmt_year(
         iso_week_based,
         sign_is_mandatory,
     }: modifier::Year,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let full_year = if iso_week_based {
         date.iso_year_week().0
     } else {
@@ -335,13 +335,13 @@ fn fmt_year(
 // region: time formatters
 /// Format the hour into the designated output.
 fn fmt_hour(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Hour {
         padding,
         is_12_hour_clock,
     }: modifier::Hour,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let value = match (time.hour(), is_12_hour_clock) {
         (hour, false) => hour,
         (0 | 12, true) => 12,
@@ -353,22 +353,22 @@ fn fmt_hour(
 
 /// Format the minute into the designated output.
 fn fmt_minute(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Minute { padding }: modifier::Minute,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<_, _, 2>(output, time.minute(), padding)
 }
 
 /// Format the period into the designated output.
 fn fmt_period(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Period {
         is_uppercase,
         case_sensitive: _, // no effect on formatting
     }: modifier::Period,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match (time.hour() >= 12, is_uppercase) {
         (false, false) => write(output, b"am"),
         (false, true) => write(output, b"AM"),
@@ -379,19 +379,19 @@ fn fmt_period(
 
 /// Format the second into the designated output.
 fn fmt_second(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Second { padding }: modifier::Second,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<_, _, 2>(output, time.second(), padding)
 }
 
 /// Format the subsecond into the designated output.
-fn fmt_subsecond<W: io::Write>(
+fn fmt_subsecond<W: Output>(
     output: &mut W,
     time: Time,
     modifier::Subsecond { digits }: modifier::Subsecond,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     use modifier::SubsecondDigits::*;
     let nanos = time.nanosecond();
 
@@ -420,13 +420,13 @@ fn fmt_subsecond<W: io::Write>(
 // region: offset formatters
 /// Format the offset hour into the designated output.
 fn fmt_offset_hour(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetHour {
         padding,
         sign_is_mandatory,
     }: modifier::OffsetHour,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let mut bytes = 0;
     if offset.is_negative() {
         bytes += write(output, &[b'-'])?;
@@ -439,19 +439,19 @@ fn fmt_offset_hour(
 
 /// Format the offset minute into the designated output.
 fn fmt_offset_minute(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetMinute { padding }: modifier::OffsetMinute,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<_, _, 2>(output, offset.minutes_past_hour().unsigned_abs(), padding)
 }
 
 /// Format the offset second into the designated output.
 fn fmt_offset_second(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetSecond { padding }: modifier::OffsetSecond,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<_, _, 2>(output, offset.seconds_past_minute().unsigned_abs(), padding)
 }
 // endregion offset formatters
@@ -0,0 +1,49 @@
+//! The destinations that formatted output can be written to.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::error;
+
+/// A destination for formatted output.
+///
+/// This is implemented for every [`io::Write`] when the `std` feature is enabled, and for every
+/// [`fmt::Write`] by way of [`FmtWriter`], so that formatting does not depend on `std`. The trait
+/// is public within a private module so that it can be used in the bounds of the sealed
+/// [`Formattable`](crate::formatting::Formattable) methods.
+pub trait Output {
+    /// Write all bytes to the output.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), error::Format>;
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write + ?Sized> Output for W {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        Ok(self.write_all(bytes)?)
+    }
+}
+
+/// An adapter allowing a [`fmt::Write`] to be used as an [`Output`].
+///
+/// Any bytes that are not valid UTF-8 are replaced with `U+FFFD REPLACEMENT CHARACTER`, matching
+/// the behavior of formatting to a `String`.
+pub(crate) struct FmtWriter<'a, W: ?Sized>(pub(crate) &'a mut W);
+
+impl<W: fmt::Write + ?Sized> Output for FmtWriter<'_, W> {
+    fn write_bytes(&mut self, mut bytes: &[u8]) -> Result<(), error::Format> {
+        loop {
+            match core::str::from_utf8(bytes) {
+                Ok(valid) => return Ok(self.0.write_str(valid)?),
+                Err(err) => {
+                    let (valid, invalid) = bytes.split_at(err.valid_up_to());
+                    if let Ok(valid) = core::str::from_utf8(valid) {
+                        self.0.write_str(valid)?;
+                    }
+                    self.0.write_char(char::REPLACEMENT_CHARACTER)?;
+                    bytes = &invalid[err.error_len().unwrap_or(invalid.len())..];
+                }
+            }
+        }
+    }
+}
@@ -26,6 +26,20 @@ use crate::Duration;
 ///
 /// This implementation allows for operations with signed [`Duration`]s, but is otherwise identical
 /// to [`std::time::Instant`].
+///
+/// `Hash` delegates to the wrapped [`std::time::Instant`], so equal instants always hash equally
+/// and an `Instant` can be used as a key in hashed collections, including lookups by the wrapped
+/// value.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use time::Instant;
+/// let instant = Instant::now();
+/// let mut timers = HashMap::new();
+/// timers.insert(instant, "start");
+/// assert_eq!(timers.get(&Instant(instant.0)), Some(&"start"));
+/// assert_eq!(timers.get(&instant.0), Some(&"start"));
+/// ```
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -56,6 +70,27 @@ impl Instant {
     pub fn elapsed(self) -> Duration {
         Self::now() - self
     }
+
+    /// Returns the signed amount of time from `origin` to `self`. Unlike [`Instant::elapsed`], the
+    /// reference point is provided explicitly, and the result is negative when `origin` is later
+    /// than `self`. This is equivalent to `self - origin`.
+    ///
+    /// ```rust
+    /// # use time::{Instant, ext::NumericalDuration};
+    /// let start = Instant::now();
+    /// let later = start + 1_234.milliseconds();
+    /// assert_eq!(later.since(start), 1_234.milliseconds());
+    /// assert_eq!(start.since(later), (-1_234).milliseconds());
+    /// assert_eq!(start.since(start), 0.seconds());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the difference cannot be represented as a [`Duration`]. Use
+    /// [`Instant::checked_signed_duration_since`] to avoid this.
+    pub fn since(self, origin: Self) -> Duration {
+        self - origin
+    }
     // endregion delegation
 
     // region: checked arithmetic
@@ -100,6 +135,28 @@ impl Instant {
             self.0.checked_add(duration.abs_std()).map(Self)
         }
     }
+
+    /// Returns the signed amount of time elapsed from `other` to `self`, or `None` if the result
+    /// cannot be represented as a [`Duration`]. Unlike [`std::time::Instant::duration_since`], the
+    /// result is negative when `other` is later than `self`.
+    ///
+    /// ```rust
+    /// # use time::{Instant, ext::NumericalDuration};
+    /// let now = Instant::now();
+    /// let later = now + 5.seconds();
+    /// assert_eq!(later.checked_signed_duration_since(now), Some(5.seconds()));
+    /// assert_eq!(now.checked_signed_duration_since(later), Some((-5).seconds()));
+    /// assert_eq!(now.checked_signed_duration_since(now), Some(0.seconds()));
+    /// ```
+    pub fn checked_signed_duration_since(self, other: Self) -> Option<Duration> {
+        match self.0.cmp(&other.0) {
+            Ordering::Equal => Some(Duration::ZERO),
+            Ordering::Greater => (self.0 - other.0).try_into().ok(),
+            Ordering::Less => Duration::try_from(other.0 - self.0)
+                .ok()
+                .map(|duration| -duration),
+        }
+    }
     // endregion checked arithmetic
 
     /// Obtain the inner [`std::time::Instant`].
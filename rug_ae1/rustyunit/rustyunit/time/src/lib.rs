@@ -18,9 +18,10 @@
 //!
 //!   Enables macros that provide compile-time verification of values and intuitive syntax.
 //!
-//! - `formatting` (_implicitly enables `std`_)
+//! - `formatting` (_implicitly enables `alloc`_)
 //!
-//!   Enables formatting of most structs.
+//!   Enables formatting of most structs. Writing directly to an [`std::io::Write`] implementor
+//!   additionally requires the `std` feature.
 //!
 //! - `parsing`
 //!
@@ -45,7 +46,10 @@
 //!
 //! - `serde`
 //!
-//!   Enables [serde](https://docs.rs/serde) support for all types except [`Instant`].
+//!   Enables [serde](https://docs.rs/serde) support for all types except [`Instant`]. An
+//!   `Instant` can be represented relative to the current instant with
+//!   [`serde::instant_relative`](crate::serde::instant_relative), which is only meaningful within
+//!   the same process.
 //!
 //! - `serde-human-readable` (_implicitly enables `serde`, `formatting`, and `parsing`_)
 //!
@@ -2,10 +2,10 @@
 
 use core::convert::TryFrom;
 use core::fmt;
-use core::num::NonZeroU8;
+use core::str::FromStr;
 
 use self::Month::*;
-use crate::error;
+use crate::{error, util};
 
 /// Months of the year.
 #[allow(clippy::missing_docs_in_private_items)] // variants
@@ -27,9 +27,18 @@ pub enum Month {
 }
 
 impl Month {
-    /// Create a `Month` from its numerical value.
-    pub(crate) const fn from_number(n: NonZeroU8) -> Result<Self, error::ComponentRange> {
-        match n.get() {
+    /// Create a `Month` from its one-indexed numerical value. This is the inverse of
+    /// [`Month::to_number`].
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::from_number(1), Ok(Month::January));
+    /// assert_eq!(Month::from_number(12), Ok(Month::December));
+    /// assert!(Month::from_number(0).is_err());
+    /// assert!(Month::from_number(13).is_err());
+    /// ```
+    pub const fn from_number(n: u8) -> Result<Self, error::ComponentRange> {
+        match n {
             1 => Ok(January),
             2 => Ok(February),
             3 => Ok(March),
@@ -52,6 +61,24 @@ impl Month {
         }
     }
 
+    /// Get the one-indexed numerical value of the month. January is `1` and December is `12`.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.to_number(), 1);
+    /// assert_eq!(Month::December.to_number(), 12);
+    ///
+    /// let mut month = Month::January;
+    /// for n in 1..=12 {
+    ///     assert_eq!(month.to_number(), n);
+    ///     assert_eq!(Month::from_number(n), Ok(month));
+    ///     month = month.next();
+    /// }
+    /// ```
+    pub const fn to_number(self) -> u8 {
+        self as _
+    }
+
     /// Get the previous month.
     ///
     /// ```rust
@@ -97,11 +124,85 @@ impl Month {
             December => January,
         }
     }
-}
 
-impl fmt::Display for Month {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
+    /// Get the month `n` months before this one, wrapping around the year as necessary.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::March.nth_prev(2), Month::January);
+    /// assert_eq!(Month::January.nth_prev(1), Month::December);
+    /// assert_eq!(Month::January.nth_prev(12), Month::January);
+    /// assert_eq!(Month::January.nth_prev(25), Month::December);
+    /// ```
+    pub const fn nth_prev(self, n: u8) -> Self {
+        let mut month = self;
+        let mut n = n % 12;
+        while n > 0 {
+            month = month.previous();
+            n -= 1;
+        }
+        month
+    }
+
+    /// Get the month `n` months after this one, wrapping around the year as necessary.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.nth_next(2), Month::March);
+    /// assert_eq!(Month::December.nth_next(1), Month::January);
+    /// assert_eq!(Month::January.nth_next(12), Month::January);
+    /// assert_eq!(Month::December.nth_next(25), Month::January);
+    /// ```
+    pub const fn nth_next(self, n: u8) -> Self {
+        let mut month = self;
+        let mut n = n % 12;
+        while n > 0 {
+            month = month.next();
+            n -= 1;
+        }
+        month
+    }
+
+    /// Get the number of days in the month of the provided year.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::February.length(2023), 28);
+    /// assert_eq!(Month::February.length(2024), 29);
+    /// assert_eq!(Month::April.length(2024), 30);
+    /// assert_eq!(Month::December.length(2024), 31);
+    /// ```
+    pub const fn length(self, year: i32) -> u8 {
+        util::days_in_year_month(year, self)
+    }
+
+    /// Get the calendar quarter the month is in.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.quarter(), 1);
+    /// assert_eq!(Month::March.quarter(), 1);
+    /// assert_eq!(Month::April.quarter(), 2);
+    /// assert_eq!(Month::October.quarter(), 4);
+    /// assert_eq!(Month::December.quarter(), 4);
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        (self as u8 - 1) / 3 + 1
+    }
+
+    /// Get the full English name of the month.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.name(), "January");
+    /// assert_eq!(Month::September.name(), "September");
+    /// assert_eq!(Month::January.as_ref(), "January");
+    /// assert_eq!(Month::January.to_string(), Month::January.name());
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
             January => "January",
             February => "February",
             March => "March",
@@ -114,13 +215,78 @@ impl fmt::Display for Month {
             October => "October",
             November => "November",
             December => "December",
-        })
+        }
+    }
+
+    /// Get the three-letter English abbreviation of the month.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.abbreviation(), "Jan");
+    /// assert_eq!(Month::September.abbreviation(), "Sep");
+    /// ```
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            January => "Jan",
+            February => "Feb",
+            March => "Mar",
+            April => "Apr",
+            May => "May",
+            June => "Jun",
+            July => "Jul",
+            August => "Aug",
+            September => "Sep",
+            October => "Oct",
+            November => "Nov",
+            December => "Dec",
+        }
+    }
+}
+
+impl fmt::Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl AsRef<str> for Month {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+/// Parse a `Month` from its full English name or its three-letter abbreviation, ignoring ASCII
+/// case. No other languages are supported.
+///
+/// ```rust
+/// # use time::{error, Month};
+/// assert_eq!("January".parse(), Ok(Month::January));
+/// assert_eq!("jan".parse(), Ok(Month::January));
+/// assert_eq!("SEPTEMBER".parse(), Ok(Month::September));
+/// assert_eq!("Sept".parse::<Month>(), Err(error::InvalidVariant));
+/// assert_eq!("Janvier".parse::<Month>(), Err(error::InvalidVariant));
+/// ```
+impl FromStr for Month {
+    type Err = error::InvalidVariant;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut month = January;
+        loop {
+            if s.eq_ignore_ascii_case(month.name()) || s.eq_ignore_ascii_case(month.abbreviation())
+            {
+                return Ok(month);
+            }
+            month = month.next();
+            if month == January {
+                return Err(error::InvalidVariant);
+            }
+        }
     }
 }
 
 impl From<Month> for u8 {
     fn from(month: Month) -> Self {
-        month as _
+        month.to_number()
     }
 }
 
@@ -128,15 +294,6 @@ impl TryFrom<u8> for Month {
     type Error = error::ComponentRange;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match NonZeroU8::new(value) {
-            Some(value) => Self::from_number(value),
-            None => Err(error::ComponentRange {
-                name: "month",
-                minimum: 1,
-                maximum: 12,
-                value: 0,
-                conditional_range: false,
-            }),
-        }
+        Self::from_number(value)
     }
 }
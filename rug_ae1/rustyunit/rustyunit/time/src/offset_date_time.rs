@@ -1,5 +1,7 @@
 //! The [`OffsetDateTime`] struct and its associated `impl`s.
 
+#[cfg(feature = "formatting")]
+use alloc::string::String;
 use core::cmp::Ordering;
 #[cfg(feature = "std")]
 use core::convert::From;
@@ -7,13 +9,13 @@ use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::{FmtWriter, Formattable};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::{error, Date, Duration, Month, PrimitiveDateTime, Time, UtcOffset, Weekday};
@@ -23,7 +25,15 @@ const UNIX_EPOCH_JULIAN_DAY: i32 = Date::__from_ordinal_date_unchecked(1970, 1).
 
 /// A [`PrimitiveDateTime`] with a [`UtcOffset`].
 ///
-/// All comparisons are performed using the UTC time.
+/// All comparisons are performed using the UTC time. Two values representing the same instant
+/// are equal and have the same ordering and hash, even if their offsets differ. To compare the
+/// date and time as they appear in each value's offset, use [`OffsetDateTime::cmp_wall_clock`].
+///
+/// ```rust
+/// # use time::macros::datetime;
+/// assert_eq!(datetime!(2019-01-01 12:00 +1), datetime!(2019-01-01 11:00 UTC));
+/// assert!(datetime!(2019-01-01 12:00 +1) < datetime!(2019-01-01 11:30 UTC));
+/// ```
 // Internally, an `OffsetDateTime` is a thin wrapper around a [`PrimitiveDateTime`] coupled with a
 // [`UtcOffset`]. This offset is added to the date, time, or datetime as necessary for presentation
 // or returning from a function.
@@ -46,6 +56,30 @@ impl OffsetDateTime {
         .midnight()
         .assume_utc();
 
+    /// The smallest value that can be represented by `OffsetDateTime`, which is
+    /// [`PrimitiveDateTime::MIN`] in UTC.
+    ///
+    /// The value of this may vary depending on the feature flags enabled.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, PrimitiveDateTime, macros::offset};
+    /// assert_eq!(OffsetDateTime::MIN, PrimitiveDateTime::MIN.assume_utc());
+    /// assert_eq!(OffsetDateTime::MIN.offset(), offset!(UTC));
+    /// ```
+    pub const MIN: Self = PrimitiveDateTime::MIN.assume_utc();
+
+    /// The largest value that can be represented by `OffsetDateTime`, which is
+    /// [`PrimitiveDateTime::MAX`] in UTC.
+    ///
+    /// The value of this may vary depending on the feature flags enabled.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, PrimitiveDateTime, macros::offset};
+    /// assert_eq!(OffsetDateTime::MAX, PrimitiveDateTime::MAX.assume_utc());
+    /// assert_eq!(OffsetDateTime::MAX.offset(), offset!(UTC));
+    /// ```
+    pub const MAX: Self = PrimitiveDateTime::MAX.assume_utc();
+
     // region: now
     /// Create a new `OffsetDateTime` with the current date and time in UTC.
     ///
@@ -57,7 +91,29 @@ impl OffsetDateTime {
     #[cfg(feature = "std")]
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     pub fn now_utc() -> Self {
-        SystemTime::now().into()
+        Self::try_now_utc().expect("system time is outside the supported range")
+    }
+
+    /// Attempt to create a new `OffsetDateTime` with the current date and time in UTC. Unlike
+    /// [`OffsetDateTime::now_utc`], this does not panic if the system clock reports a time that
+    /// cannot be represented, instead returning
+    /// [`Error::ConversionRange`](crate::Error::ConversionRange).
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::offset};
+    /// let now = OffsetDateTime::try_now_utc()?;
+    /// assert!(now.year() >= 2019);
+    /// assert_eq!(now.offset(), offset!(UTC));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn try_now_utc() -> Result<Self, error::Error> {
+        let now = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => Self::UNIX_EPOCH.checked_add_std(duration),
+            Err(err) => Self::UNIX_EPOCH.checked_sub_std(err.duration()),
+        };
+        now.ok_or_else(|| error::ConversionRange.into())
     }
 
     /// Attempt to create a new `OffsetDateTime` with the current date and time in the local offset.
@@ -79,6 +135,9 @@ impl OffsetDateTime {
 
     /// Convert the `OffsetDateTime` from the current [`UtcOffset`] to the provided [`UtcOffset`].
     ///
+    /// The instant represented is unchanged. To keep the date and time while changing the offset,
+    /// use [`OffsetDateTime::replace_offset`].
+    ///
     /// ```rust
     /// # use time::macros::{datetime, offset};
     /// assert_eq!(
@@ -105,6 +164,108 @@ impl OffsetDateTime {
         }
     }
 
+    /// Convert the `OffsetDateTime` to the provided [`UtcOffset`], keeping the instant represented.
+    /// The date and time will change to reflect the new offset. This is an alias of
+    /// [`OffsetDateTime::to_offset`].
+    ///
+    /// Contrast this with [`OffsetDateTime::reinterpret_offset`], which keeps the date and time
+    /// but changes the instant represented.
+    ///
+    /// ```rust
+    /// # use time::macros::{datetime, offset};
+    /// let datetime = datetime!(2000-01-01 0:00 UTC).convert_to_offset(offset!(-5));
+    /// assert_eq!(datetime, datetime!(2000-01-01 0:00 UTC));
+    /// assert_eq!(datetime.hour(), 19);
+    /// assert_eq!(datetime.offset(), offset!(-5));
+    /// ```
+    pub const fn convert_to_offset(self, offset: UtcOffset) -> Self {
+        self.to_offset(offset)
+    }
+
+    /// Replace the [`UtcOffset`], keeping the date and time. The instant represented will change
+    /// unless the offsets are equal. This is an alias of [`OffsetDateTime::replace_offset`].
+    ///
+    /// Contrast this with [`OffsetDateTime::convert_to_offset`], which keeps the instant represented
+    /// but changes the date and time.
+    ///
+    /// ```rust
+    /// # use time::macros::{datetime, offset};
+    /// let datetime = datetime!(2000-01-01 0:00 UTC).reinterpret_offset(offset!(-5));
+    /// assert_eq!(datetime, datetime!(2000-01-01 0:00 -5));
+    /// assert_ne!(datetime, datetime!(2000-01-01 0:00 UTC));
+    /// assert_eq!(datetime.hour(), 0);
+    /// assert_eq!(datetime.offset(), offset!(-5));
+    /// ```
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn reinterpret_offset(self, offset: UtcOffset) -> Self {
+        self.replace_offset(offset)
+    }
+
+    /// Obtain the [`PrimitiveDateTime`] in UTC, discarding the offset. This is the same value that
+    /// would be obtained by converting to UTC and then dropping the offset.
+    ///
+    /// ```rust
+    /// # use time::macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2000-01-01 0:00 +1).to_utc_primitive(),
+    ///     datetime!(1999-12-31 23:00),
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2000-01-01 0:00 +1).to_utc_primitive(),
+    ///     datetime!(2000-01-01 0:00 +1).to_offset(offset!(UTC)).to_local_primitive(),
+    /// );
+    /// ```
+    pub const fn to_utc_primitive(self) -> PrimitiveDateTime {
+        self.utc_datetime
+    }
+
+    /// Obtain the [`PrimitiveDateTime`] in the stored offset, discarding the offset. This is the
+    /// wall-clock date and time, as opposed to the one in UTC returned by
+    /// [`to_utc_primitive`](Self::to_utc_primitive).
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2000-01-01 0:00 +1).to_local_primitive(),
+    ///     datetime!(2000-01-01 0:00),
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2000-01-01 0:00 UTC).to_local_primitive(),
+    ///     datetime!(2000-01-01 0:00 UTC).to_utc_primitive(),
+    /// );
+    /// ```
+    pub const fn to_local_primitive(self) -> PrimitiveDateTime {
+        self.utc_datetime.utc_to_offset(self.offset)
+    }
+
+    /// Obtain the [`Date`] and [`Time`] of the instant in the provided [`UtcOffset`]. This is
+    /// equivalent to converting to the offset and then obtaining the date and time, but does not
+    /// construct an intermediate `OffsetDateTime`.
+    ///
+    /// ```rust
+    /// # use time::macros::{date, datetime, offset, time};
+    /// let datetime = datetime!(2000-01-01 2:00 UTC);
+    /// assert_eq!(
+    ///     datetime.components_in_offset(offset!(-5)),
+    ///     (date!(1999 - 12 - 31), time!(21:00))
+    /// );
+    /// assert_eq!(
+    ///     datetime.components_in_offset(offset!(+10)),
+    ///     (date!(2000 - 01 - 01), time!(12:00))
+    /// );
+    /// assert_eq!(
+    ///     datetime.components_in_offset(offset!(+10)),
+    ///     (
+    ///         datetime.to_offset(offset!(+10)).date(),
+    ///         datetime.to_offset(offset!(+10)).time()
+    ///     )
+    /// );
+    /// ```
+    pub const fn components_in_offset(self, offset: UtcOffset) -> (Date, Time) {
+        let local = self.utc_datetime.utc_to_offset(offset);
+        (local.date, local.time)
+    }
+
     // region: constructors
     /// Create an `OffsetDateTime` from the provided Unix timestamp. Calling `.offset()` on the
     /// resulting value is guaranteed to return UTC.
@@ -491,6 +652,7 @@ impl OffsetDateTime {
     }
 
     /// Get the Julian day for the date. The time is not taken into account for this calculation.
+    /// The date is the one in the value's own offset, not UTC.
     ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
     /// freely available [here](https://www.researchgate.net/publication/316558298_Date_Algorithms).
@@ -501,6 +663,7 @@ impl OffsetDateTime {
     /// assert_eq!(datetime!(2000-01-01 0:00 UTC).to_julian_day(), 2_451_545);
     /// assert_eq!(datetime!(2019-01-01 0:00 UTC).to_julian_day(), 2_458_485);
     /// assert_eq!(datetime!(2019-12-31 0:00 UTC).to_julian_day(), 2_458_849);
+    /// assert_eq!(datetime!(2019-12-31 23:00 -1).to_julian_day(), 2_458_849);
     /// ```
     pub const fn to_julian_day(self) -> i32 {
         self.date().to_julian_day()
@@ -682,6 +845,21 @@ impl OffsetDateTime {
     pub const fn nanosecond(self) -> u32 {
         self.utc_datetime.nanosecond()
     }
+
+    /// Get the fraction of the day that has elapsed in the stored offset.
+    ///
+    /// The returned value will always be in the range `0.0..1.0`.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(2019-01-01 0:00 UTC).day_fraction(), 0.);
+    /// assert_eq!(datetime!(2019-01-01 12:00 UTC).day_fraction(), 0.5);
+    /// assert_eq!(datetime!(2019-01-01 12:00 +6).day_fraction(), 0.5);
+    /// assert!(datetime!(2019-01-01 23:59:59.999_999_999 UTC).day_fraction() < 1.);
+    /// ```
+    pub fn day_fraction(self) -> f64 {
+        self.time().day_fraction()
+    }
     // endregion time getters
     // endregion getters
 
@@ -727,6 +905,184 @@ impl OffsetDateTime {
         let offset_datetime = self.utc_datetime.utc_to_offset(self.offset);
         Some(const_try_opt!(offset_datetime.checked_sub(duration)).assume_offset(self.offset))
     }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use core::time::Duration as StdDuration;
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time::macros::{datetime, offset};
+    /// let datetime = Date::MAX.midnight().assume_offset(offset!(+10));
+    /// assert_eq!(datetime.checked_add_std(2.std_days()), None);
+    /// assert_eq!(datetime.checked_add_std(StdDuration::MAX), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30 +10).checked_add_std(27.std_hours()),
+    ///     Some(datetime!(2019 - 11 - 26 18:30 +10))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        if duration.as_secs() > i64::MAX as u64 {
+            return None;
+        }
+        self.checked_add(Duration::new_unchecked(
+            duration.as_secs() as _,
+            duration.subsec_nanos() as _,
+        ))
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use core::time::Duration as StdDuration;
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time::macros::{datetime, offset};
+    /// let datetime = Date::MIN.midnight().assume_offset(offset!(+10));
+    /// assert_eq!(datetime.checked_sub_std(2.std_days()), None);
+    /// assert_eq!(datetime.checked_sub_std(StdDuration::MAX), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30 +10).checked_sub_std(27.std_hours()),
+    ///     Some(datetime!(2019 - 11 - 24 12:30 +10))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        if duration.as_secs() > i64::MAX as u64 {
+            return None;
+        }
+        self.checked_sub(Duration::new_unchecked(
+            duration.as_secs() as _,
+            duration.subsec_nanos() as _,
+        ))
+    }
+
+    /// Computes `self` shifted forward by the provided number of calendar months in the stored
+    /// offset, returning `None` if the resulting year is out of range. The time and offset are
+    /// unchanged, and the day saturates to the last day of the resulting month.
+    ///
+    /// ```
+    /// # use time::{Date, macros::{datetime, offset}};
+    /// assert_eq!(
+    ///     datetime!(2020 - 01 - 31 15:30 +10).checked_add_months(1),
+    ///     Some(datetime!(2020 - 02 - 29 15:30 +10))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021 - 01 - 31 15:30 -5).checked_add_months(1),
+    ///     Some(datetime!(2021 - 02 - 28 15:30 -5))
+    /// );
+    /// assert_eq!(
+    ///     Date::MAX.midnight().assume_offset(offset!(+1)).checked_add_months(1),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_add_months(self, months: i32) -> Option<Self> {
+        let offset_datetime = self.utc_datetime.utc_to_offset(self.offset);
+        Some(const_try_opt!(offset_datetime.checked_add_months(months)).assume_offset(self.offset))
+    }
+
+    /// Computes `self` shifted backward by the provided number of calendar months in the stored
+    /// offset, returning `None` if the resulting year is out of range. The time and offset are
+    /// unchanged, and the day saturates to the last day of the resulting month.
+    ///
+    /// ```
+    /// # use time::{Date, macros::{datetime, offset}};
+    /// assert_eq!(
+    ///     datetime!(2020 - 03 - 31 15:30 +10).checked_sub_months(1),
+    ///     Some(datetime!(2020 - 02 - 29 15:30 +10))
+    /// );
+    /// assert_eq!(
+    ///     Date::MIN.midnight().assume_offset(offset!(-1)).checked_sub_months(1),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_sub_months(self, months: i32) -> Option<Self> {
+        let offset_datetime = self.utc_datetime.utc_to_offset(self.offset);
+        Some(const_try_opt!(offset_datetime.checked_sub_months(months)).assume_offset(self.offset))
+    }
+
+    /// Computes `self` shifted forward by the provided number of calendar years in the stored
+    /// offset, returning `None` if the resulting year is out of range. The time and offset are
+    /// unchanged, and February 29 becomes February 28 when the resulting year is not a leap year.
+    ///
+    /// ```
+    /// # use time::{Date, macros::{datetime, offset}};
+    /// assert_eq!(
+    ///     datetime!(2020 - 02 - 29 15:30 +10).checked_add_years(1),
+    ///     Some(datetime!(2021 - 02 - 28 15:30 +10))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021 - 01 - 31 15:30 -5).checked_add_years(3),
+    ///     Some(datetime!(2024 - 01 - 31 15:30 -5))
+    /// );
+    /// assert_eq!(
+    ///     Date::MAX.midnight().assume_offset(offset!(+1)).checked_add_years(1),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_add_years(self, years: i32) -> Option<Self> {
+        let offset_datetime = self.utc_datetime.utc_to_offset(self.offset);
+        Some(const_try_opt!(offset_datetime.checked_add_years(years)).assume_offset(self.offset))
+    }
+
+    /// Computes `self` shifted backward by the provided number of calendar years in the stored
+    /// offset, returning `None` if the resulting year is out of range. The time and offset are
+    /// unchanged, and February 29 becomes February 28 when the resulting year is not a leap year.
+    ///
+    /// ```
+    /// # use time::{Date, macros::{datetime, offset}};
+    /// assert_eq!(
+    ///     datetime!(2020 - 02 - 29 15:30 +10).checked_sub_years(1),
+    ///     Some(datetime!(2019 - 02 - 28 15:30 +10))
+    /// );
+    /// assert_eq!(
+    ///     Date::MIN.midnight().assume_offset(offset!(-1)).checked_sub_years(1),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_sub_years(self, years: i32) -> Option<Self> {
+        let offset_datetime = self.utc_datetime.utc_to_offset(self.offset);
+        Some(const_try_opt!(offset_datetime.checked_sub_years(years)).assume_offset(self.offset))
+    }
+
+    /// Computes `self` shifted by the provided number of calendar years, months, and days in the
+    /// stored offset, returning `None` if the resulting year is out of range. The time and offset
+    /// are unchanged.
+    ///
+    /// This behaves exactly as [`PrimitiveDateTime::checked_add_calendar`] does on the local date
+    /// and time: the years are applied, then the months, then the days. The day saturates after
+    /// each of the first two steps, and every intermediate value must be in range.
+    ///
+    /// ```
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30 +10).checked_add_calendar(1, 6, 0),
+    ///     Some(datetime!(2021 - 05 - 25 15:30 +10))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30 +10).checked_add_calendar(0, -11, 7),
+    ///     Some(datetime!(2019 - 01 - 01 15:30 +10))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2020 - 01 - 31 0:00 UTC).checked_add_calendar(0, 1, 0),
+    ///     Some(datetime!(2020 - 02 - 29 0:00 UTC))
+    /// );
+    /// // The years are applied before the months, so the day is clamped to February 28 first.
+    /// assert_eq!(
+    ///     datetime!(2020 - 02 - 29 15:30 -5).checked_add_calendar(1, 1, 0),
+    ///     Some(datetime!(2021 - 03 - 28 15:30 -5))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30 UTC).checked_add_calendar(i32::MAX, 0, 0),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_add_calendar(self, years: i32, months: i32, days: i64) -> Option<Self> {
+        let offset_datetime = self.utc_datetime.utc_to_offset(self.offset);
+        Some(
+            const_try_opt!(offset_datetime.checked_add_calendar(years, months, days))
+                .assume_offset(self.offset),
+        )
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -798,6 +1154,247 @@ impl OffsetDateTime {
         }
     }
     // endregion: saturating arithmetic
+
+    // region: rounding
+    /// Round the `OffsetDateTime` down to the nearest multiple of `interval`, measured from the
+    /// Unix epoch in UTC. The offset is preserved.
+    ///
+    /// Boundaries only line up with the local clock when `interval` evenly divides a day and the
+    /// offset is a multiple of `interval`. Otherwise, the result is still a multiple of `interval`
+    /// from the Unix epoch, which may not correspond to a round local time. If `interval` is not
+    /// positive, the value is returned unchanged.
+    ///
+    /// ```rust
+    /// # use time::{Duration, macros::datetime};
+    /// assert_eq!(
+    ///     datetime!(2021-01-01 10:07:30 UTC).floor_to(Duration::minutes(15)),
+    ///     datetime!(2021-01-01 10:00 UTC)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-01-01 10:07:30 +1).floor_to(Duration::minutes(15)),
+    ///     datetime!(2021-01-01 10:00 +1)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-01-01 10:00 UTC).floor_to(Duration::minutes(15)),
+    ///     datetime!(2021-01-01 10:00 UTC)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(1969-12-31 23:59:59.5 UTC).floor_to(Duration::SECOND),
+    ///     datetime!(1969-12-31 23:59:59 UTC)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resulting value would be out of range.
+    pub fn floor_to(self, interval: Duration) -> Self {
+        let interval = interval.whole_nanoseconds();
+        if interval <= 0 {
+            return self;
+        }
+
+        let timestamp = self.unix_timestamp_nanos();
+        Self::from_unix_timestamp_nanos(timestamp - timestamp.rem_euclid(interval))
+            .expect("resulting value is out of range")
+            .to_offset(self.offset)
+    }
+
+    /// Round the `OffsetDateTime` up to the nearest multiple of `interval`, measured from the Unix
+    /// epoch in UTC. The offset is preserved.
+    ///
+    /// Boundaries only line up with the local clock when `interval` evenly divides a day and the
+    /// offset is a multiple of `interval`. Otherwise, the result is still a multiple of `interval`
+    /// from the Unix epoch, which may not correspond to a round local time. If `interval` is not
+    /// positive, the value is returned unchanged.
+    ///
+    /// ```rust
+    /// # use time::{Duration, macros::datetime};
+    /// assert_eq!(
+    ///     datetime!(2021-01-01 10:07:30 UTC).ceil_to(Duration::minutes(15)),
+    ///     datetime!(2021-01-01 10:15 UTC)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-01-01 23:50 -5).ceil_to(Duration::minutes(15)),
+    ///     datetime!(2021-01-02 0:00 -5)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-01-01 10:00 UTC).ceil_to(Duration::minutes(15)),
+    ///     datetime!(2021-01-01 10:00 UTC)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resulting value would be out of range.
+    pub fn ceil_to(self, interval: Duration) -> Self {
+        let interval = interval.whole_nanoseconds();
+        if interval <= 0 {
+            return self;
+        }
+
+        let timestamp = self.unix_timestamp_nanos();
+        let remainder = timestamp.rem_euclid(interval);
+        if remainder == 0 {
+            return self;
+        }
+
+        Self::from_unix_timestamp_nanos(timestamp - remainder + interval)
+            .expect("resulting value is out of range")
+            .to_offset(self.offset)
+    }
+
+    /// Truncate the `OffsetDateTime` to the second, setting the subsecond component to zero. The
+    /// date, the rest of the time, and the offset are unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789_012_345 +1).truncate_to_second(),
+    ///     datetime!(2019-01-01 12:34:56 +1)
+    /// );
+    /// ```
+    pub const fn truncate_to_second(self) -> Self {
+        // Offsets are whole seconds, so truncating in UTC is the same as truncating in the offset.
+        Self {
+            utc_datetime: self.utc_datetime.truncate_to_second(),
+            offset: self.offset,
+        }
+    }
+
+    /// Truncate the `OffsetDateTime` to the millisecond, setting the digits beyond the
+    /// millisecond to zero. The date, the rest of the time, and the offset are unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789_012_345 +1).truncate_to_millisecond(),
+    ///     datetime!(2019-01-01 12:34:56.789 +1)
+    /// );
+    /// ```
+    pub const fn truncate_to_millisecond(self) -> Self {
+        Self {
+            utc_datetime: self.utc_datetime.truncate_to_millisecond(),
+            offset: self.offset,
+        }
+    }
+
+    /// Truncate the `OffsetDateTime` to the microsecond, setting the digits beyond the
+    /// microsecond to zero. The date, the rest of the time, and the offset are unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789_012_345 +1).truncate_to_microsecond(),
+    ///     datetime!(2019-01-01 12:34:56.789_012 +1)
+    /// );
+    /// ```
+    pub const fn truncate_to_microsecond(self) -> Self {
+        Self {
+            utc_datetime: self.utc_datetime.truncate_to_microsecond(),
+            offset: self.offset,
+        }
+    }
+    // endregion rounding
+
+    // region: comparison
+    /// Whether `self` and `other` represent instants no more than `tolerance` apart. The offsets
+    /// of the two values are not taken into account.
+    ///
+    /// ```
+    /// # use time::{Duration, OffsetDateTime, ext::NumericalDuration};
+    /// # use time::macros::datetime;
+    /// let a = datetime!(2019 - 11 - 25 15:30 UTC);
+    /// assert!(a.approx_eq(a + 1.milliseconds(), 1.milliseconds()));
+    /// assert!(a.approx_eq(a - 999.microseconds(), 1.milliseconds()));
+    /// assert!(!a.approx_eq(a + 1_001.microseconds(), 1.milliseconds()));
+    /// assert!(a.approx_eq(datetime!(2019 - 11 - 25 16:30 +1), 0.seconds()));
+    /// assert!(OffsetDateTime::MIN.approx_eq(OffsetDateTime::MAX, Duration::MAX));
+    /// assert!(!OffsetDateTime::MAX.approx_eq(OffsetDateTime::MIN, 1.days()));
+    /// ```
+    pub fn approx_eq(self, other: Self, tolerance: Duration) -> bool {
+        let (earlier, later) = if self.utc_datetime <= other.utc_datetime {
+            (self.utc_datetime, other.utc_datetime)
+        } else {
+            (other.utc_datetime, self.utc_datetime)
+        };
+        later - earlier <= tolerance
+    }
+
+    /// Get the number of calendar days from `other` to `self`. The time of day is not taken into
+    /// account; each value's date is taken in its own offset.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// let a = datetime!(2019 - 01 - 02 0:00 UTC);
+    /// assert_eq!(a.diff_calendar_days(datetime!(2019 - 01 - 01 23:59 UTC)), 1);
+    /// assert_eq!(a.diff_calendar_days(datetime!(2019 - 01 - 31 0:00 UTC)), -29);
+    ///
+    /// // Two hours apart, but on different days in their respective offsets.
+    /// let a = datetime!(2019 - 01 - 02 0:30 +1);
+    /// let b = datetime!(2019 - 01 - 01 23:30 +2);
+    /// assert_eq!(a.diff_calendar_days(b), 1);
+    ///
+    /// // The same instant, but on different days in their respective offsets.
+    /// let a = datetime!(2019 - 01 - 01 23:00 -1);
+    /// let b = datetime!(2019 - 01 - 02 0:00 UTC);
+    /// assert_eq!(a.diff_calendar_days(b), -1);
+    /// ```
+    pub const fn diff_calendar_days(self, other: Self) -> i64 {
+        self.to_julian_day() as i64 - other.to_julian_day() as i64
+    }
+
+    /// Check whether `self` and `other` fall on the same calendar day, with each value's date
+    /// taken in its own offset.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// let a = datetime!(2019 - 01 - 01 23:00 -1);
+    /// assert!(a.is_same_local_day(datetime!(2019 - 01 - 01 0:00 UTC)));
+    /// assert!(a.is_same_local_day(datetime!(2019 - 01 - 01 0:00 +1)));
+    /// // The same instant, but on different days in their respective offsets.
+    /// assert!(!a.is_same_local_day(datetime!(2019 - 01 - 02 0:00 UTC)));
+    /// ```
+    pub const fn is_same_local_day(self, other: Self) -> bool {
+        self.to_julian_day() == other.to_julian_day()
+    }
+
+    /// Check whether `self` and `other` fall on the same calendar day in UTC, regardless of the
+    /// offset of either value.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// let a = datetime!(2019 - 01 - 01 23:00 -1);
+    /// assert!(a.is_same_utc_day(datetime!(2019 - 01 - 02 0:00 UTC)));
+    /// assert!(a.is_same_utc_day(datetime!(2019 - 01 - 02 23:59 +1)));
+    /// assert!(!a.is_same_utc_day(datetime!(2019 - 01 - 01 23:00 UTC)));
+    /// ```
+    pub const fn is_same_utc_day(self, other: Self) -> bool {
+        self.utc_datetime.date().to_julian_day() == other.utc_datetime.date().to_julian_day()
+    }
+
+    /// Compare the date and time of `self` and `other` as they appear in their respective
+    /// offsets, ignoring the offsets themselves. This differs from the [`Ord`] implementation,
+    /// which compares the instants represented.
+    ///
+    /// ```rust
+    /// # use core::cmp::Ordering;
+    /// # use time::macros::datetime;
+    /// let a = datetime!(2019 - 01 - 01 12:00 +2);
+    /// let b = datetime!(2019 - 01 - 01 11:00 UTC);
+    /// assert_eq!(a.cmp(&b), Ordering::Less);
+    /// assert_eq!(a.cmp_wall_clock(b), Ordering::Greater);
+    ///
+    /// // The same wall clock time in different offsets.
+    /// let c = datetime!(2019 - 01 - 01 12:00 UTC);
+    /// assert_eq!(a.cmp_wall_clock(c), Ordering::Equal);
+    /// assert_ne!(a, c);
+    /// ```
+    pub fn cmp_wall_clock(self, other: Self) -> Ordering {
+        self.utc_datetime
+            .utc_to_offset(self.offset)
+            .cmp(&other.utc_datetime.utc_to_offset(other.offset))
+    }
+    // endregion comparison
 }
 
 // region: replacement
@@ -872,6 +1469,9 @@ impl OffsetDateTime {
 
     /// Replace the offset. The date and time components remain unchanged.
     ///
+    /// The instant represented will change unless the offsets are equal. To keep the instant while
+    /// changing the offset, use [`OffsetDateTime::to_offset`].
+    ///
     /// ```rust
     /// # use time::macros::{datetime, offset};
     /// assert_eq!(
@@ -883,14 +1483,105 @@ impl OffsetDateTime {
     pub const fn replace_offset(self, offset: UtcOffset) -> Self {
         self.utc_datetime.assume_offset(offset)
     }
+
+    /// Replace the time with one created from the number of seconds since midnight, which is
+    /// assumed to be in the stored offset. The date and offset components are unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 5:00 -5).replace_time_from_secs_f64(3_723.000_004)?,
+    ///     datetime!(2020-01-01 1:02:03.000_004 -5)
+    /// );
+    /// assert!(datetime!(2020-01-01 0:00 UTC)
+    ///     .replace_time_from_secs_f64(86_400.)
+    ///     .is_err());
+    /// assert!(datetime!(2020-01-01 0:00 UTC)
+    ///     .replace_time_from_secs_f64(f64::NAN)
+    ///     .is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn replace_time_from_secs_f64(
+        self,
+        secs_since_midnight: f64,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(self.replace_time(Time::from_seconds_since_midnight_f64(secs_since_midnight)?))
+    }
+
+    /// Replace the milliseconds within the second, which is assumed to be in the stored offset.
+    /// Any sub-millisecond precision is discarded. The date, offset, and other time components
+    /// are unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 12:34:56.789_012_345 -5).replace_millisecond(0),
+    ///     Ok(datetime!(2020-01-01 12:34:56 -5))
+    /// );
+    /// assert!(datetime!(2020-01-01 12:00 UTC).replace_millisecond(1_000).is_err());
+    /// ```
+    pub const fn replace_millisecond(
+        self,
+        millisecond: u16,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(self.replace_time(const_try!(self.time().replace_millisecond(millisecond))))
+    }
+
+    /// Replace the microseconds within the second, which is assumed to be in the stored offset.
+    /// Any sub-microsecond precision is discarded. The date, offset, and other time components
+    /// are unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 12:34:56.789_012_345 -5).replace_microsecond(7_008),
+    ///     Ok(datetime!(2020-01-01 12:34:56.007_008 -5))
+    /// );
+    /// assert!(datetime!(2020-01-01 12:00 UTC).replace_microsecond(1_000_000).is_err());
+    /// ```
+    pub const fn replace_microsecond(
+        self,
+        microsecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(self.replace_time(const_try!(self.time().replace_microsecond(microsecond))))
+    }
+
+    /// Replace the nanoseconds within the second, which is assumed to be in the stored offset. The
+    /// date, offset, and other time components are unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 12:34:56.789_012_345 -5).replace_nanosecond(7_008_009),
+    ///     Ok(datetime!(2020-01-01 12:34:56.007_008_009 -5))
+    /// );
+    /// assert!(datetime!(2020-01-01 12:00 UTC).replace_nanosecond(1_000_000_000).is_err());
+    /// ```
+    pub const fn replace_nanosecond(self, nanosecond: u32) -> Result<Self, error::ComponentRange> {
+        Ok(self.replace_time(const_try!(self.time().replace_nanosecond(nanosecond))))
+    }
 }
 // endregion replacement
 
 // region: formatting & parsing
 #[cfg(feature = "formatting")]
 impl OffsetDateTime {
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     /// Format the `OffsetDateTime` using the provided [format
-    /// description](crate::format_description).
+    /// description](crate::format_description), writing the result directly into `output`. The
+    /// number of bytes written is returned.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Rfc3339, macros::datetime};
+    /// let mut buf = Vec::new();
+    /// let bytes = datetime!(2020-01-02 03:04:05 +06:07).format_into(&mut buf, &Rfc3339)?;
+    /// assert_eq!(bytes, 25);
+    /// assert_eq!(buf, b"2020-01-02T03:04:05+06:07");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -905,6 +1596,34 @@ impl OffsetDateTime {
         )
     }
 
+    /// Format the `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), writing the result into a [`fmt::Write`]
+    /// implementor such as a `String`.
+    ///
+    /// Unlike [`format_into`](Self::format_into), this does not require the `std` feature.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Rfc3339, macros::datetime};
+    /// let mut buf = String::new();
+    /// datetime!(2020-01-02 03:04:05 +06:07).format_into_fmt(&mut buf, &Rfc3339)?;
+    /// assert_eq!(buf, "2020-01-02T03:04:05+06:07");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<(), error::Format> {
+        let local = self.utc_datetime.utc_to_offset(self.offset);
+        format.format_into(
+            &mut FmtWriter(output),
+            Some(local.date),
+            Some(local.time),
+            Some(self.offset),
+        )?;
+        Ok(())
+    }
+
     /// Format the `OffsetDateTime` using the provided [format
     /// description](crate::format_description).
     ///
@@ -924,6 +1643,64 @@ impl OffsetDateTime {
         let local = self.utc_datetime.utc_to_offset(self.offset);
         format.format(Some(local.date), Some(local.time), Some(self.offset))
     }
+
+    /// Format the date of the `OffsetDateTime` in its offset as an ISO 8601 week date of the form
+    /// `YYYY-Www-D`. The year is the ISO week-based year, which may differ from the calendar year
+    /// near the start and end of a year. The inverse is [`Date::parse_iso_week_date`].
+    ///
+    /// Years with more than four digits, which are only possible with the `large-dates` feature,
+    /// are always preceded by a sign, as ISO 8601 requires for the expanded representation. This
+    /// allows every value to be parsed back.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Date};
+    /// assert_eq!(
+    ///     datetime!(2024-01-01 0:00 UTC).format_iso_week_date()?,
+    ///     "2024-W01-1"
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-01-01 12:00 +01:00).format_iso_week_date()?,
+    ///     "2020-W53-5"
+    /// );
+    ///
+    /// let datetime = datetime!(2024-12-30 0:00 UTC);
+    /// let formatted = datetime.format_iso_week_date()?;
+    /// assert_eq!(formatted, "2025-W01-1");
+    /// assert_eq!(Date::parse_iso_week_date(&formatted)?, datetime.date());
+    ///
+    /// for &date in &[Date::MIN, Date::MAX] {
+    ///     let formatted = date.midnight().assume_utc().format_iso_week_date()?;
+    ///     assert_eq!(Date::parse_iso_week_date(&formatted)?, date);
+    /// }
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_iso_week_date(self) -> Result<String, error::Format> {
+        self.format(crate::format_description::ISO_WEEK_DATE)
+    }
+
+    /// Format the `OffsetDateTime` as an [RFC 3339](https://tools.ietf.org/html/rfc3339) string.
+    /// This is equivalent to calling [`OffsetDateTime::format`] with
+    /// [`Rfc3339`](crate::format_description::well_known::Rfc3339).
+    ///
+    /// RFC 3339 requires a four-digit year and an offset without a seconds component, so an error
+    /// is returned for values outside of these constraints.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05.123 +06:07).format_rfc3339()?,
+    ///     "2020-01-02T03:04:05.123+06:07"
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05 UTC).format_rfc3339()?,
+    ///     "2020-01-02T03:04:05Z"
+    /// );
+    /// assert!(datetime!(2020-01-02 03:04:05 +06:07:08).format_rfc3339().is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_rfc3339(self) -> Result<String, error::Format> {
+        self.format(&crate::format_description::well_known::Rfc3339)
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -949,6 +1726,32 @@ impl OffsetDateTime {
     ) -> Result<Self, error::Parse> {
         description.parse_offset_date_time(input.as_bytes())
     }
+
+    /// Parse an `OffsetDateTime` from an [RFC 3339](https://tools.ietf.org/html/rfc3339) string.
+    /// This is equivalent to calling [`OffsetDateTime::parse`] with
+    /// [`Rfc3339`](crate::format_description::well_known::Rfc3339).
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_rfc3339("2020-01-02T03:04:05.123+06:07")?,
+    ///     datetime!(2020-01-02 03:04:05.123 +06:07)
+    /// );
+    /// // The sign is kept when the offset is less than one hour.
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_rfc3339("2020-01-02T03:04:05-00:30")?,
+    ///     datetime!(2020-01-02 03:04:05 -00:30)
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_rfc3339("2020-01-02T03:04:05Z")?,
+    ///     datetime!(2020-01-02 03:04:05 UTC)
+    /// );
+    /// assert!(OffsetDateTime::parse_rfc3339("2020-01-02 03:04:05").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_rfc3339(input: &str) -> Result<Self, error::Parse> {
+        Self::parse(input, &crate::format_description::well_known::Rfc3339)
+    }
 }
 
 impl fmt::Display for OffsetDateTime {
@@ -1000,6 +1803,34 @@ where
 
 impl_add_assign!(OffsetDateTime: Duration, StdDuration);
 
+/// Shift the value by `(years, months)` in the stored offset, as
+/// [`checked_add_calendar`](OffsetDateTime::checked_add_calendar) does. If the day does not exist
+/// after either step, the last day of that month is used.
+///
+/// ```rust
+/// # use time::macros::datetime;
+/// assert_eq!(
+///     datetime!(2019 - 11 - 25 15:30 +10) + (1, 6),
+///     datetime!(2021 - 05 - 25 15:30 +10)
+/// );
+/// assert_eq!(
+///     datetime!(2019 - 08 - 31 0:00 UTC) + (0, 6),
+///     datetime!(2020 - 02 - 29 0:00 UTC)
+/// );
+/// ```
+///
+/// # Panics
+///
+/// This may panic if an overflow occurs.
+impl Add<(i32, i32)> for OffsetDateTime {
+    type Output = Self;
+
+    fn add(self, (years, months): (i32, i32)) -> Self::Output {
+        self.checked_add_calendar(years, months, 0)
+            .expect("resulting value is out of range")
+    }
+}
+
 impl<T> Sub<T> for OffsetDateTime
 where
     PrimitiveDateTime: Sub<T, Output = PrimitiveDateTime>,
@@ -1015,6 +1846,34 @@ where
 
 impl_sub_assign!(OffsetDateTime: Duration, StdDuration);
 
+/// Shift the value by `(-years, -months)` in the stored offset, as
+/// [`checked_add_calendar`](OffsetDateTime::checked_add_calendar) does. If the day does not exist
+/// after either step, the last day of that month is used.
+///
+/// ```rust
+/// # use time::macros::datetime;
+/// assert_eq!(
+///     datetime!(2021 - 05 - 25 15:30 +10) - (1, 6),
+///     datetime!(2019 - 11 - 25 15:30 +10)
+/// );
+/// assert_eq!(
+///     datetime!(2020 - 03 - 31 0:00 UTC) - (0, 1),
+///     datetime!(2020 - 02 - 29 0:00 UTC)
+/// );
+/// ```
+///
+/// # Panics
+///
+/// This may panic if an overflow occurs.
+impl Sub<(i32, i32)> for OffsetDateTime {
+    type Output = Self;
+
+    fn sub(self, (years, months): (i32, i32)) -> Self::Output {
+        self.checked_add_calendar(-years, -months, 0)
+            .expect("resulting value is out of range")
+    }
+}
+
 impl Sub for OffsetDateTime {
     type Output = Duration;
 
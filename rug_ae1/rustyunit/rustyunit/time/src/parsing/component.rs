@@ -45,7 +45,7 @@ pub(crate) fn parse_month(
         match modifiers.repr {
             modifier::MonthRepr::Numerical => {
                 return exactly_n_digits_padded::<_, 2>(modifiers.padding)(input)?
-                    .flat_map(|n| Month::from_number(n).ok());
+                    .flat_map(|n: NonZeroU8| Month::from_number(n.get()).ok());
             }
             modifier::MonthRepr::Long => [
                 (&b"January"[..], January),
@@ -208,13 +208,25 @@ pub(crate) fn parse_period(
     input: &[u8],
     modifiers: modifier::Period,
 ) -> Option<ParsedItem<'_, Period>> {
+    if !modifiers.case_sensitive {
+        return first_match(
+            [
+                (&b"am"[..], Period::Am),
+                (&b"pm"[..], Period::Pm),
+                (&b"a.m."[..], Period::Am),
+                (&b"p.m."[..], Period::Pm),
+            ],
+            false,
+        )(input);
+    }
+
     first_match(
         if modifiers.is_uppercase {
             [(&b"AM"[..], Period::Am), (&b"PM"[..], Period::Pm)]
         } else {
             [(&b"am"[..], Period::Am), (&b"pm"[..], Period::Pm)]
         },
-        modifiers.case_sensitive,
+        true,
     )(input)
 }
 
@@ -239,10 +251,22 @@ pub(crate) fn parse_subsecond(
                 any_digit(input)?.map(|v| (v - b'0') as u32 * 100_000_000);
 
             let mut multiplier = 10_000_000;
-            while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
-                value += (digit - b'0') as u32 * multiplier;
+            let mut num_digits = 1;
+            while num_digits < 9 {
+                match any_digit(input) {
+                    Some(ParsedItem(new_input, digit)) => {
+                        value += (digit - b'0') as u32 * multiplier;
+                        input = new_input;
+                        multiplier /= 10;
+                        num_digits += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            // Any digits beyond the ninth are truncated, as rounding could carry into the second.
+            while let Some(ParsedItem(new_input, _)) = any_digit(input) {
                 input = new_input;
-                multiplier /= 10;
             }
 
             ParsedItem(input, value)
@@ -253,16 +277,19 @@ pub(crate) fn parse_subsecond(
 
 // region: offset components
 /// Parse the "hour" component of a `UtcOffset`.
+///
+/// Returns the hour and whether the offset is negative, as the latter cannot be determined from
+/// the hour alone when it is zero.
 pub(crate) fn parse_offset_hour(
     input: &[u8],
     modifiers: modifier::OffsetHour,
-) -> Option<ParsedItem<'_, i8>> {
+) -> Option<ParsedItem<'_, (i8, bool)>> {
     let ParsedItem(input, sign) = opt(sign)(input);
     let ParsedItem(input, hour) = exactly_n_digits_padded::<u8, 2>(modifiers.padding)(input)?;
     match sign {
-        Some(b'-') => Some(ParsedItem(input, -(hour as i8))),
+        Some(b'-') => Some(ParsedItem(input, (-(hour as i8), true))),
         None if modifiers.sign_is_mandatory => None,
-        _ => Some(ParsedItem(input, hour as i8)),
+        _ => Some(ParsedItem(input, (hour as i8, false))),
     }
 }
 
@@ -1,6 +1,7 @@
 //! A trait that can be used to parse an item from an input.
 
 use core::convert::TryInto;
+use core::num::NonZeroU8;
 use core::ops::Deref;
 
 use crate::error::TryFromParsed;
@@ -43,10 +44,13 @@ mod sealed {
         /// remain after parsing, an error will be returned.
         fn parse(&self, input: &[u8]) -> Result<Parsed, error::Parse> {
             let mut parsed = Parsed::new();
-            if self.parse_into(input, &mut parsed)?.is_empty() {
+            let remaining = self.parse_into(input, &mut parsed)?;
+            if remaining.is_empty() {
                 Ok(parsed)
             } else {
-                Err(error::Parse::UnexpectedTrailingCharacters)
+                Err(error::Parse::UnexpectedTrailingCharacters {
+                    position: input.len() - remaining.len(),
+                })
             }
         }
 
@@ -84,7 +88,9 @@ impl sealed::Sealed for FormatItem<'_> {
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_item(input, self)?)
+        parsed
+            .parse_item_at(input, self)
+            .map_err(|(err, remaining)| error::Parse::at(err, input.len(), remaining))
     }
 }
 
@@ -94,7 +100,9 @@ impl sealed::Sealed for [FormatItem<'_>] {
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_items(input, self)?)
+        parsed
+            .parse_items_at(input, self)
+            .map_err(|(err, remaining)| error::Parse::at(err, input.len(), remaining))
     }
 }
 
@@ -127,6 +135,8 @@ impl sealed::Sealed for Rfc2822 {
 
         let colon = ascii_char::<b':'>;
         let comma = ascii_char::<b','>;
+        let input_len = input.len();
+        let at = |err, remaining: &[u8]| error::Parse::at(err, input_len, remaining);
 
         let input = opt(fws)(input).into_inner();
         let input = first_match(
@@ -141,14 +151,20 @@ impl sealed::Sealed for Rfc2822 {
             ],
             false,
         )(input)
-        .ok_or(InvalidComponent("weekday"))?
+        .ok_or_else(|| at(InvalidComponent("weekday"), input))?
         .assign_value_to(&mut parsed.weekday);
-        let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = comma(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
+        let input = cfws(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = n_to_m_digits::<_, 1, 2>(input)
-            .ok_or(InvalidComponent("day"))?
+            .ok_or_else(|| at(InvalidComponent("day"), input))?
             .assign_value_to(&mut parsed.day);
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = cfws(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = first_match(
             [
                 (&b"Jan"[..], Month::January),
@@ -166,9 +182,11 @@ impl sealed::Sealed for Rfc2822 {
             ],
             false,
         )(input)
-        .ok_or(InvalidComponent("month"))?
+        .ok_or_else(|| at(InvalidComponent("month"), input))?
         .assign_value_to(&mut parsed.month);
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = cfws(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = match exactly_n_digits::<u32, 4>(input) {
             Some(item) => {
                 let input = item
@@ -178,43 +196,54 @@ impl sealed::Sealed for Rfc2822 {
                         } else {
                             Err(InvalidComponent("year"))
                         }
-                    })?
+                    })
+                    .map_err(|err| at(err, input))?
                     .map(|year| year as _)
                     .assign_value_to(&mut parsed.year);
-                let input = fws(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = fws(input)
+                    .ok_or_else(|| at(InvalidLiteral, input))?
+                    .into_inner();
                 input
             }
             None => {
                 let input = exactly_n_digits::<u32, 2>(input)
-                    .ok_or(InvalidComponent("year"))?
+                    .ok_or_else(|| at(InvalidComponent("year"), input))?
                     .map(|year| if year < 50 { year + 2000 } else { year + 1900 })
                     .map(|year| year as _)
                     .assign_value_to(&mut parsed.year);
-                let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = cfws(input)
+                    .ok_or_else(|| at(InvalidLiteral, input))?
+                    .into_inner();
                 input
             }
         };
 
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("hour"))?
+            .ok_or_else(|| at(InvalidComponent("hour"), input))?
             .assign_value_to(&mut parsed.hour_24);
         let input = opt(cfws)(input).into_inner();
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = colon(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = opt(cfws)(input).into_inner();
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("minute"))?
+            .ok_or_else(|| at(InvalidComponent("minute"), input))?
             .assign_value_to(&mut parsed.minute);
 
         let input = if let Some(input) = colon(opt(cfws)(input).into_inner()) {
             let input = input.into_inner(); // discard the colon
             let input = opt(cfws)(input).into_inner();
             let input = exactly_n_digits::<_, 2>(input)
-                .ok_or(InvalidComponent("second"))?
+                .ok_or_else(|| at(InvalidComponent("second"), input))?
                 .assign_value_to(&mut parsed.second);
-            let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = cfws(input)
+                .ok_or_else(|| at(InvalidLiteral, input))?
+                .into_inner();
             input
         } else {
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+            cfws(input)
+                .ok_or_else(|| at(InvalidLiteral, input))?
+                .into_inner()
         };
 
         // The RFC explicitly allows leap seconds. We don't currently support them, so treat it as
@@ -253,9 +282,10 @@ impl sealed::Sealed for Rfc2822 {
             return Ok(input);
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, offset_sign) =
+            sign(input).ok_or_else(|| at(InvalidComponent("offset hour"), input))?;
         let input = exactly_n_digits::<u8, 2>(input)
-            .ok_or(InvalidComponent("offset hour"))?
+            .ok_or_else(|| at(InvalidComponent("offset hour"), input))?
             .map(|offset_hour| {
                 if offset_sign == b'-' {
                     -(offset_hour as i8)
@@ -264,8 +294,9 @@ impl sealed::Sealed for Rfc2822 {
                 }
             })
             .assign_value_to(&mut parsed.offset_hour);
+        parsed.offset_is_negative = Some(offset_sign == b'-');
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("offset minute"))?
+            .ok_or_else(|| at(InvalidComponent("offset minute"), input))?
             .assign_value_to(&mut parsed.offset_minute);
 
         Ok(input)
@@ -285,38 +316,48 @@ impl sealed::Sealed for Rfc3339 {
 
         let dash = ascii_char::<b'-'>;
         let colon = ascii_char::<b':'>;
+        let input_len = input.len();
+        let at = |err, remaining: &[u8]| error::Parse::at(err, input_len, remaining);
 
         let input = exactly_n_digits::<_, 4>(input)
-            .ok_or(InvalidComponent("year"))?
+            .ok_or_else(|| at(InvalidComponent("year"), input))?
             .map(|year: u32| year as _)
             .assign_value_to(&mut parsed.year);
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = dash(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("month"))?
-            .flat_map_res(Month::from_number)
+            .ok_or_else(|| at(InvalidComponent("month"), input))?
+            .flat_map_res(|month: NonZeroU8| Month::from_number(month.get()))
             .map_err(error::TryFromParsed::ComponentRange)?
             .assign_value_to(&mut parsed.month);
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = dash(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("day"))?
+            .ok_or_else(|| at(InvalidComponent("day"), input))?
             .assign_value_to(&mut parsed.day);
         let input = ascii_char_ignore_case::<b'T'>(input)
-            .ok_or(InvalidLiteral)?
+            .ok_or_else(|| at(InvalidLiteral, input))?
             .into_inner();
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("hour"))?
+            .ok_or_else(|| at(InvalidComponent("hour"), input))?
             .assign_value_to(&mut parsed.hour_24);
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = colon(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("minute"))?
+            .ok_or_else(|| at(InvalidComponent("minute"), input))?
             .assign_value_to(&mut parsed.minute);
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = colon(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("second"))?
+            .ok_or_else(|| at(InvalidComponent("second"), input))?
             .assign_value_to(&mut parsed.second);
         let input = if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
             let ParsedItem(mut input, mut value) = any_digit(input)
-                .ok_or(InvalidComponent("subsecond"))?
+                .ok_or_else(|| at(InvalidComponent("subsecond"), input))?
                 .map(|v| (v - b'0') as u32 * 100_000_000);
 
             let mut multiplier = 10_000_000;
@@ -345,9 +386,10 @@ impl sealed::Sealed for Rfc3339 {
             return Ok(input);
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, offset_sign) =
+            sign(input).ok_or_else(|| at(InvalidComponent("offset hour"), input))?;
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("offset hour"))?
+            .ok_or_else(|| at(InvalidComponent("offset hour"), input))?
             .map(|offset_hour: u8| {
                 if offset_sign == b'-' {
                     -(offset_hour as i8)
@@ -356,9 +398,12 @@ impl sealed::Sealed for Rfc3339 {
                 }
             })
             .assign_value_to(&mut parsed.offset_hour);
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        parsed.offset_is_negative = Some(offset_sign == b'-');
+        let input = colon(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let input = exactly_n_digits::<_, 2>(input)
-            .ok_or(InvalidComponent("offset minute"))?
+            .ok_or_else(|| at(InvalidComponent("offset minute"), input))?
             .assign_value_to(&mut parsed.offset_minute);
 
         Ok(input)
@@ -372,30 +417,40 @@ impl sealed::Sealed for Rfc3339 {
 
         let dash = ascii_char::<b'-'>;
         let colon = ascii_char::<b':'>;
+        let input_len = input.len();
+        let at = |err, remaining: &[u8]| error::Parse::at(err, input_len, remaining);
 
         let ParsedItem(input, year) =
-            exactly_n_digits::<u32, 4>(input).ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, month) =
-            exactly_n_digits::<_, 2>(input).ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            exactly_n_digits::<u32, 4>(input).ok_or_else(|| at(InvalidComponent("year"), input))?;
+        let input = dash(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
+        let ParsedItem(input, month) = exactly_n_digits::<NonZeroU8, 2>(input)
+            .ok_or_else(|| at(InvalidComponent("month"), input))?;
+        let input = dash(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let ParsedItem(input, day) =
-            exactly_n_digits::<_, 2>(input).ok_or(InvalidComponent("day"))?;
+            exactly_n_digits::<_, 2>(input).ok_or_else(|| at(InvalidComponent("day"), input))?;
         let input = ascii_char_ignore_case::<b'T'>(input)
-            .ok_or(InvalidLiteral)?
+            .ok_or_else(|| at(InvalidLiteral, input))?
             .into_inner();
         let ParsedItem(input, hour) =
-            exactly_n_digits::<_, 2>(input).ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            exactly_n_digits::<_, 2>(input).ok_or_else(|| at(InvalidComponent("hour"), input))?;
+        let input = colon(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let ParsedItem(input, minute) =
-            exactly_n_digits::<_, 2>(input).ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            exactly_n_digits::<_, 2>(input).ok_or_else(|| at(InvalidComponent("minute"), input))?;
+        let input = colon(input)
+            .ok_or_else(|| at(InvalidLiteral, input))?
+            .into_inner();
         let ParsedItem(input, mut second) =
-            exactly_n_digits::<_, 2>(input).ok_or(InvalidComponent("second"))?;
+            exactly_n_digits::<_, 2>(input).ok_or_else(|| at(InvalidComponent("second"), input))?;
         let ParsedItem(input, mut nanosecond) =
             if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
                 let ParsedItem(mut input, mut value) = any_digit(input)
-                    .ok_or(InvalidComponent("subsecond"))?
+                    .ok_or_else(|| at(InvalidComponent("subsecond"), input))?
                     .map(|v| (v - b'0') as u32 * 100_000_000);
 
                 let mut multiplier = 10_000_000;
@@ -414,37 +469,39 @@ impl sealed::Sealed for Rfc3339 {
                 ParsedItem(input, UtcOffset::UTC)
             } else {
                 let ParsedItem(input, offset_sign) =
-                    sign(input).ok_or(InvalidComponent("offset hour"))?;
-                let ParsedItem(input, offset_hour) =
-                    exactly_n_digits::<u8, 2>(input).ok_or(InvalidComponent("offset hour"))?;
-                let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-                let ParsedItem(input, offset_minute) =
-                    exactly_n_digits::<u8, 2>(input).ok_or(InvalidComponent("offset minute"))?;
-                UtcOffset::from_hms(
-                    if offset_sign == b'-' {
-                        -(offset_hour as i8)
-                    } else {
-                        offset_hour as _
-                    },
-                    offset_minute as _,
-                    0,
-                )
-                .map(|offset| ParsedItem(input, offset))
-                .map_err(|mut err| {
-                    // Provide the user a more accurate error.
-                    if err.name == "hours" {
-                        err.name = "offset hour";
-                    } else if err.name == "minutes" {
-                        err.name = "offset minute";
-                    }
-                    err
-                })
-                .map_err(TryFromParsed::ComponentRange)?
+                    sign(input).ok_or_else(|| at(InvalidComponent("offset hour"), input))?;
+                let ParsedItem(input, offset_hour) = exactly_n_digits::<u8, 2>(input)
+                    .ok_or_else(|| at(InvalidComponent("offset hour"), input))?;
+                let input = colon(input)
+                    .ok_or_else(|| at(InvalidLiteral, input))?
+                    .into_inner();
+                let ParsedItem(input, offset_minute) = exactly_n_digits::<u8, 2>(input)
+                    .ok_or_else(|| at(InvalidComponent("offset minute"), input))?;
+                // Both components are negated so that the sign is kept when the hour is zero.
+                let (offset_hour, offset_minute) = if offset_sign == b'-' {
+                    (-(offset_hour as i8), -(offset_minute as i8))
+                } else {
+                    (offset_hour as _, offset_minute as _)
+                };
+                UtcOffset::from_hms(offset_hour, offset_minute, 0)
+                    .map(|offset| ParsedItem(input, offset))
+                    .map_err(|mut err| {
+                        // Provide the user a more accurate error.
+                        if err.name == "hours" {
+                            err.name = "offset hour";
+                        } else if err.name == "minutes" {
+                            err.name = "offset minute";
+                        }
+                        err
+                    })
+                    .map_err(TryFromParsed::ComponentRange)?
             }
         };
 
         if !input.is_empty() {
-            return Err(error::Parse::UnexpectedTrailingCharacters);
+            return Err(error::Parse::UnexpectedTrailingCharacters {
+                position: input_len - input.len(),
+            });
         }
 
         // The RFC explicitly allows leap seconds. We don't currently support them, so treat it as
@@ -454,7 +511,7 @@ impl sealed::Sealed for Rfc3339 {
             nanosecond = 999_999_999;
         }
 
-        Ok(Month::from_number(month)
+        Ok(Month::from_number(month.get())
             .and_then(|month| Date::from_calendar_date(year as _, month, day))
             .and_then(|date| date.with_hms_nano(hour, minute, second, nanosecond))
             .map(|date| date.assume_offset(offset))
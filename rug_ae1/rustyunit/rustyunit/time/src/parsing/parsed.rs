@@ -63,6 +63,9 @@ pub struct Parsed {
     pub(crate) offset_minute: Option<u8>,
     /// Seconds within the minute of the UTC offset.
     pub(crate) offset_second: Option<u8>,
+    /// Whether the UTC offset is negative. This is only used when the whole hours of the offset
+    /// are zero, as the sign is otherwise stored with the hour.
+    pub(crate) offset_is_negative: Option<bool>,
 }
 
 impl Parsed {
@@ -89,6 +92,7 @@ impl Parsed {
             offset_hour: None,
             offset_minute: None,
             offset_second: None,
+            offset_is_negative: None,
         }
     }
 
@@ -102,16 +106,31 @@ impl Parsed {
         input: &'a [u8],
         item: &FormatItem<'_>,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_item_at(input, item).map_err(|(err, _)| err)
+    }
+
+    /// Parse a single [`FormatItem`] as with [`Parsed::parse_item`]. On failure, the input that
+    /// remained at the literal or component that could not be parsed is returned alongside the
+    /// error.
+    pub(crate) fn parse_item_at<'a>(
+        &mut self,
+        input: &'a [u8],
+        item: &FormatItem<'_>,
+    ) -> Result<&'a [u8], (error::ParseFromDescription, &'a [u8])> {
         match item {
-            FormatItem::Literal(literal) => Self::parse_literal(input, literal),
-            FormatItem::Component(component) => self.parse_component(input, *component),
-            FormatItem::Compound(compound) => self.parse_items(input, compound),
-            FormatItem::Optional(item) => self.parse_item(input, item).or(Ok(input)),
+            FormatItem::Literal(literal) => {
+                Self::parse_literal(input, literal).map_err(|err| (err, input))
+            }
+            FormatItem::Component(component) => self
+                .parse_component(input, *component)
+                .map_err(|err| (err, input)),
+            FormatItem::Compound(compound) => self.parse_items_at(input, compound),
+            FormatItem::Optional(item) => self.parse_item_at(input, item).or(Ok(input)),
             FormatItem::First(items) => {
                 let mut first_err = None;
 
                 for item in items.iter() {
-                    match self.parse_item(input, item) {
+                    match self.parse_item_at(input, item) {
                         Ok(remaining_input) => return Ok(remaining_input),
                         Err(err) if first_err.is_none() => first_err = Some(err),
                         Err(_) => {}
@@ -135,14 +154,25 @@ impl Parsed {
     /// be mutated in this instance.
     pub fn parse_items<'a>(
         &mut self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         items: &[FormatItem<'_>],
     ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_items_at(input, items).map_err(|(err, _)| err)
+    }
+
+    /// Parse a sequence of [`FormatItem`]s as with [`Parsed::parse_items`]. On failure, the input
+    /// that remained at the literal or component that could not be parsed is returned alongside
+    /// the error.
+    pub(crate) fn parse_items_at<'a>(
+        &mut self,
+        mut input: &'a [u8],
+        items: &[FormatItem<'_>],
+    ) -> Result<&'a [u8], (error::ParseFromDescription, &'a [u8])> {
         // Make a copy that we can mutate. It will only be set to the user's copy if everything
         // succeeds.
         let mut this = *self;
         for item in items {
-            input = this.parse_item(input, item)?;
+            input = this.parse_item_at(input, item)?;
         }
         *self = this;
         Ok(input)
@@ -227,9 +257,12 @@ impl Parsed {
             Component::Subsecond(modifiers) => Ok(parse_subsecond(input, modifiers)
                 .ok_or(InvalidComponent("subsecond"))?
                 .assign_value_to(&mut self.subsecond)),
-            Component::OffsetHour(modifiers) => Ok(parse_offset_hour(input, modifiers)
-                .ok_or(InvalidComponent("offset hour"))?
-                .assign_value_to(&mut self.offset_hour)),
+            Component::OffsetHour(modifiers) => {
+                let ParsedItem(input, (hour, is_negative)) =
+                    parse_offset_hour(input, modifiers).ok_or(InvalidComponent("offset hour"))?;
+                self.offset_is_negative = Some(is_negative);
+                Ok(ParsedItem(input, hour).assign_value_to(&mut self.offset_hour))
+            }
             Component::OffsetMinute(modifiers) => Ok(parse_offset_minute(input, modifiers)
                 .ok_or(InvalidComponent("offset minute"))?
                 .assign_value_to(&mut self.offset_minute)),
@@ -360,6 +393,125 @@ impl Parsed {
     }
 }
 
+/// Methods that set or build multiple fields at once.
+impl Parsed {
+    /// Set the whole hours, minutes, and seconds of the UTC offset.
+    ///
+    /// The sign is preserved for negative offsets of less than one hour.
+    ///
+    /// ```rust
+    /// # use core::convert::TryFrom;
+    /// # use time::{macros::offset, parsing::Parsed, UtcOffset};
+    /// for &offset in &[offset!(-0:30), offset!(-0:00:30), offset!(+0:30), offset!(-5:30)] {
+    ///     let mut parsed = Parsed::new();
+    ///     assert!(parsed.set_offset(offset).is_some());
+    ///     assert_eq!(UtcOffset::try_from(parsed), Ok(offset));
+    /// }
+    /// ```
+    pub fn set_offset(&mut self, value: UtcOffset) -> Option<()> {
+        let (hours, minutes, seconds) = value.as_hms();
+        self.offset_hour = Some(hours);
+        self.offset_minute = Some(minutes.unsigned_abs());
+        self.offset_second = Some(seconds.unsigned_abs());
+        self.offset_is_negative = Some(value.is_negative());
+        Some(())
+    }
+
+    /// Set the whole hours, minutes, and seconds of the UTC offset and return `self`.
+    ///
+    /// The sign is preserved for negative offsets of less than one hour.
+    ///
+    /// ```rust
+    /// # use core::convert::TryFrom;
+    /// # use time::{macros::offset, parsing::Parsed, UtcOffset};
+    /// for &offset in &[offset!(-0:30), offset!(-0:00:30), offset!(+0:30), offset!(-5:30)] {
+    ///     let parsed = Parsed::new().with_offset(offset);
+    ///     assert_eq!(parsed.map(UtcOffset::try_from), Some(Ok(offset)));
+    /// }
+    /// ```
+    pub const fn with_offset(mut self, value: UtcOffset) -> Option<Self> {
+        let (hours, minutes, seconds) = value.as_hms();
+        self.offset_hour = Some(hours);
+        self.offset_minute = Some(minutes.unsigned_abs());
+        self.offset_second = Some(seconds.unsigned_abs());
+        self.offset_is_negative = Some(value.is_negative());
+        Some(self)
+    }
+}
+
+/// Finalizer methods
+///
+/// These methods validate the information present and construct the requested type. They are
+/// equivalent to the corresponding `TryFrom<Parsed>` implementations.
+impl Parsed {
+    /// Construct a [`Date`] from the information present.
+    ///
+    /// Any of the following sets of fields are sufficient:
+    ///
+    /// - year and ordinal
+    /// - year, month, and day
+    /// - ISO year, ISO week number, and weekday
+    /// - year, Sunday-based week number, and weekday
+    /// - year, Monday-based week number, and weekday
+    pub fn into_date(self) -> Result<Date, error::TryFromParsed> {
+        self.try_into()
+    }
+
+    /// Construct a [`Time`] from the information present.
+    ///
+    /// Either the 24-hour clock hour or the 12-hour clock hour and AM/PM are required. The minute
+    /// is required unless only the hour is present on a 12-hour clock. The second and subsecond
+    /// default to zero.
+    pub fn into_time(self) -> Result<Time, error::TryFromParsed> {
+        self.try_into()
+    }
+
+    /// Construct a [`UtcOffset`] from the information present.
+    ///
+    /// The offset hour is required. The offset minute and second default to zero.
+    pub fn into_offset(self) -> Result<UtcOffset, error::TryFromParsed> {
+        self.try_into()
+    }
+
+    /// Construct a [`PrimitiveDateTime`] from the information present.
+    ///
+    /// The fields required by both [`Parsed::into_date`] and [`Parsed::into_time`] are needed.
+    pub fn into_primitive_date_time(self) -> Result<PrimitiveDateTime, error::TryFromParsed> {
+        self.try_into()
+    }
+
+    /// Construct an [`OffsetDateTime`] from the information present.
+    ///
+    /// The fields required by [`Parsed::into_date`], [`Parsed::into_time`], and
+    /// [`Parsed::into_offset`] are needed.
+    ///
+    /// ```rust
+    /// # use core::num::NonZeroU8;
+    /// # use time::{parsing::Parsed, macros::{datetime, offset}, Month};
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_year(2021);
+    /// parsed.set_month(Month::March);
+    /// parsed.set_day(NonZeroU8::new(14).unwrap());
+    /// parsed.set_hour_24(15);
+    /// parsed.set_minute(9);
+    /// parsed.set_second(26);
+    /// parsed.set_subsecond(535_897_932);
+    /// parsed.set_offset(offset!(-5:30));
+    /// assert_eq!(
+    ///     parsed.into_offset_date_time()?,
+    ///     datetime!(2021-03-14 15:09:26.535_897_932 -5:30)
+    /// );
+    ///
+    /// parsed = Parsed::new();
+    /// parsed.set_year(2021);
+    /// assert!(parsed.into_offset_date_time().is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn into_offset_date_time(self) -> Result<OffsetDateTime, error::TryFromParsed> {
+        self.try_into()
+    }
+}
+
 impl TryFrom<Parsed> for Date {
     type Error = error::TryFromParsed;
 
@@ -446,9 +598,14 @@ impl TryFrom<Parsed> for UtcOffset {
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
         let hour = parsed.offset_hour.ok_or(InsufficientInformation)?;
-        let minute = parsed.offset_minute.unwrap_or(0);
-        let second = parsed.offset_second.unwrap_or(0);
-        Self::from_hms(hour, minute as i8, second as i8).map_err(|mut err| {
+        let mut minute = parsed.offset_minute.unwrap_or(0) as i8;
+        let mut second = parsed.offset_second.unwrap_or(0) as i8;
+        // The sign cannot be stored with a zero hour, so it is tracked separately.
+        if hour == 0 && parsed.offset_is_negative == Some(true) {
+            minute = -minute;
+            second = -second;
+        }
+        Self::from_hms(hour, minute, second).map_err(|mut err| {
             // Provide the user a more accurate error.
             if err.name == "hours" {
                 err.name = "offset hour";
@@ -1,15 +1,17 @@
 //! The [`PrimitiveDateTime`] struct and its associated `impl`s.
 
+#[cfg(feature = "formatting")]
+use alloc::string::String;
+use core::cmp::Ordering;
 use core::fmt;
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 
-#[cfg(any(feature = "formatting", feature = "parsing"))]
 use crate::error;
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::{FmtWriter, Formattable};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::{util, Date, Duration, Month, OffsetDateTime, Time, UtcOffset, Weekday};
@@ -32,9 +34,9 @@ impl PrimitiveDateTime {
     /// 2. With `large-dates` enabled it is equal to `-999999 - 01 - 01 00:00:00.0`
     ///
     /// ```rust
-    /// # use time::{PrimitiveDateTime, macros::datetime};
-    /// // Assuming `large-dates` feature is enabled.
-    /// assert_eq!(PrimitiveDateTime::MIN, datetime!(-999999 - 01 - 01 0:00));
+    /// # use time::{Date, PrimitiveDateTime, Time};
+    /// assert_eq!(PrimitiveDateTime::MIN.date(), Date::MIN);
+    /// assert_eq!(PrimitiveDateTime::MIN.time(), Time::MIDNIGHT);
     /// ```
     pub const MIN: Self = Self::new(Date::MIN, Time::MIN);
 
@@ -46,9 +48,9 @@ impl PrimitiveDateTime {
     /// 2. With `large-dates` enabled it is equal to `999999 - 12 - 31 23:59:59.999_999_999`
     ///
     /// ```rust
-    /// # use time::{PrimitiveDateTime, macros::datetime};
-    /// // Assuming `large-dates` feature is enabled.
-    /// assert_eq!(PrimitiveDateTime::MAX, datetime!(+999999 - 12 - 31 23:59:59.999_999_999));
+    /// # use time::{Date, PrimitiveDateTime, macros::time};
+    /// assert_eq!(PrimitiveDateTime::MAX.date(), Date::MAX);
+    /// assert_eq!(PrimitiveDateTime::MAX.time(), time!(23:59:59.999_999_999));
     /// ```
     pub const MAX: Self = Self::new(Date::MAX, Time::MAX);
 
@@ -65,6 +67,26 @@ impl PrimitiveDateTime {
         Self { date, time }
     }
 
+    /// Create a `PrimitiveDateTime` from the provided Unix timestamp, treating the result as a
+    /// datetime in UTC. This is equivalent to obtaining the UTC date and time of
+    /// [`OffsetDateTime::from_unix_timestamp`].
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_unix_timestamp(0),
+    ///     Ok(datetime!(1970-01-01 0:00)),
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_unix_timestamp(1_546_300_800),
+    ///     Ok(datetime!(2019-01-01 0:00)),
+    /// );
+    /// assert!(PrimitiveDateTime::from_unix_timestamp(i64::MAX).is_err());
+    /// ```
+    pub const fn from_unix_timestamp(timestamp: i64) -> Result<Self, error::ComponentRange> {
+        Ok(const_try!(OffsetDateTime::from_unix_timestamp(timestamp)).utc_datetime)
+    }
+
     // region: component getters
     /// Get the [`Date`] component of the `PrimitiveDateTime`.
     ///
@@ -152,6 +174,21 @@ impl PrimitiveDateTime {
         self.date.iso_week()
     }
 
+    /// Get the ISO 8601 year and week number. The ISO year can differ from the calendar year for
+    /// dates near the beginning or end of the year.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(2019-10-04 0:00).iso_year_week(), (2019, 40));
+    /// assert_eq!(datetime!(2018-12-31 0:00).iso_year_week(), (2019, 1));
+    /// assert_eq!(datetime!(2019-12-30 0:00).iso_year_week(), (2020, 1));
+    /// assert_eq!(datetime!(2021-01-03 23:59).iso_year_week(), (2020, 53));
+    /// assert_eq!(datetime!(2021-01-04 0:00).iso_year_week(), (2021, 1));
+    /// ```
+    pub const fn iso_year_week(self) -> (i32, u8) {
+        self.date.iso_year_week()
+    }
+
     /// Get the week number where week 1 begins on the first Sunday.
     ///
     /// The returned value will always be in the range `0..=53`.
@@ -453,6 +490,45 @@ impl PrimitiveDateTime {
             offset: UtcOffset::UTC,
         }
     }
+
+    /// Get the [Unix timestamp](https://en.wikipedia.org/wiki/Unix_time), treating the
+    /// `PrimitiveDateTime` as a datetime in UTC. Any subsecond component is discarded.
+    ///
+    /// This is the inverse of [`PrimitiveDateTime::from_unix_timestamp`], and is equivalent to
+    /// calling [`OffsetDateTime::unix_timestamp`] on the result of
+    /// [`PrimitiveDateTime::assume_utc`].
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(1970-01-01 0:00).unix_timestamp(), 0);
+    /// assert_eq!(datetime!(2019-01-01 0:00).unix_timestamp(), 1_546_300_800);
+    /// assert_eq!(datetime!(1969-12-31 23:59:59.5).unix_timestamp(), -1);
+    /// ```
+    pub const fn unix_timestamp(self) -> i64 {
+        self.assume_utc().unix_timestamp()
+    }
+
+    /// Assuming that the existing `PrimitiveDateTime` represents a moment in the system's local
+    /// time zone, return an [`OffsetDateTime`]. The offset used is the one in effect at that
+    /// moment, which may differ from the current offset. If the offset cannot be determined, an
+    /// error is returned.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// let datetime = datetime!(2019-01-01 0:00).assume_local_offset();
+    /// # if false {
+    /// assert!(datetime.is_ok());
+    /// # }
+    /// ```
+    #[cfg(feature = "local-offset")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "local-offset")))]
+    pub fn assume_local_offset(self) -> Result<OffsetDateTime, error::IndeterminateOffset> {
+        // The offset depends on the instant, which in turn depends on the offset. Treat the value
+        // as UTC for an initial guess, then look up the offset again at the resulting instant.
+        let offset = UtcOffset::local_offset_at(self.assume_utc())?;
+        let offset = UtcOffset::local_offset_at(self.assume_offset(offset))?;
+        Ok(self.assume_offset(offset))
+    }
     // endregion attach offset
 
     // region: checked arithmetic
@@ -515,6 +591,219 @@ impl PrimitiveDateTime {
             time,
         })
     }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use core::time::Duration as StdDuration;
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time::macros::datetime;
+    /// let datetime = Date::MAX.midnight();
+    /// assert_eq!(datetime.checked_add_std(1.std_days()), None);
+    /// assert_eq!(datetime.checked_add_std(StdDuration::MAX), None);
+    /// assert_eq!(
+    ///     datetime.checked_add_std(86_399_999_999_999.std_nanoseconds()),
+    ///     Some(Date::MAX.with_hms_nano(23, 59, 59, 999_999_999).unwrap())
+    /// );
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30).checked_add_std(27.std_hours()),
+    ///     Some(datetime!(2019 - 11 - 26 18:30))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        if duration.as_secs() > i64::MAX as u64 {
+            return None;
+        }
+        self.checked_add(Duration::new_unchecked(
+            duration.as_secs() as _,
+            duration.subsec_nanos() as _,
+        ))
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use core::time::Duration as StdDuration;
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time::macros::datetime;
+    /// let datetime = Date::MIN.midnight();
+    /// assert_eq!(datetime.checked_sub_std(1.std_nanoseconds()), None);
+    /// assert_eq!(datetime.checked_sub_std(StdDuration::MAX), None);
+    /// assert_eq!(
+    ///     Date::MIN.with_hms(1, 0, 0).unwrap().checked_sub_std(1.std_hours()),
+    ///     Some(datetime)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30).checked_sub_std(27.std_hours()),
+    ///     Some(datetime!(2019 - 11 - 24 12:30))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        if duration.as_secs() > i64::MAX as u64 {
+            return None;
+        }
+        self.checked_sub(Duration::new_unchecked(
+            duration.as_secs() as _,
+            duration.subsec_nanos() as _,
+        ))
+    }
+
+    /// Computes `self` shifted forward by the provided number of calendar months, returning `None`
+    /// if the resulting year is out of range. The time is unchanged, and the day saturates to the
+    /// last day of the resulting month.
+    ///
+    /// ```
+    /// # use time::{Date, macros::datetime};
+    /// assert_eq!(
+    ///     datetime!(2020 - 01 - 31 15:30).checked_add_months(1),
+    ///     Some(datetime!(2020 - 02 - 29 15:30))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021 - 01 - 31 15:30).checked_add_months(13),
+    ///     Some(datetime!(2022 - 02 - 28 15:30))
+    /// );
+    /// assert_eq!(Date::MAX.midnight().checked_add_months(1), None);
+    /// ```
+    pub const fn checked_add_months(self, months: i32) -> Option<Self> {
+        Some(Self {
+            date: const_try_opt!(self.date.checked_add_months(months)),
+            time: self.time,
+        })
+    }
+
+    /// Computes `self` shifted backward by the provided number of calendar months, returning
+    /// `None` if the resulting year is out of range. The time is unchanged, and the day saturates
+    /// to the last day of the resulting month.
+    ///
+    /// ```
+    /// # use time::{Date, macros::datetime};
+    /// assert_eq!(
+    ///     datetime!(2020 - 03 - 31 15:30).checked_sub_months(1),
+    ///     Some(datetime!(2020 - 02 - 29 15:30))
+    /// );
+    /// assert_eq!(Date::MIN.midnight().checked_sub_months(1), None);
+    /// ```
+    pub const fn checked_sub_months(self, months: i32) -> Option<Self> {
+        Some(Self {
+            date: const_try_opt!(self.date.checked_sub_months(months)),
+            time: self.time,
+        })
+    }
+
+    /// Computes `self` shifted forward by the provided number of calendar years, returning `None`
+    /// if the resulting year is out of range. The time is unchanged, and February 29 becomes
+    /// February 28 when the resulting year is not a leap year.
+    ///
+    /// ```
+    /// # use time::{Date, macros::datetime};
+    /// assert_eq!(
+    ///     datetime!(2020 - 02 - 29 15:30).checked_add_years(1),
+    ///     Some(datetime!(2021 - 02 - 28 15:30))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2020 - 02 - 29 15:30).checked_add_years(8),
+    ///     Some(datetime!(2028 - 02 - 29 15:30))
+    /// );
+    /// assert_eq!(Date::MAX.midnight().checked_add_years(1), None);
+    /// ```
+    pub const fn checked_add_years(self, years: i32) -> Option<Self> {
+        Some(Self {
+            date: const_try_opt!(self.date.checked_add_years(years)),
+            time: self.time,
+        })
+    }
+
+    /// Computes `self` shifted backward by the provided number of calendar years, returning
+    /// `None` if the resulting year is out of range. The time is unchanged, and February 29
+    /// becomes February 28 when the resulting year is not a leap year.
+    ///
+    /// ```
+    /// # use time::{Date, macros::datetime};
+    /// assert_eq!(
+    ///     datetime!(2020 - 02 - 29 15:30).checked_sub_years(1),
+    ///     Some(datetime!(2019 - 02 - 28 15:30))
+    /// );
+    /// assert_eq!(Date::MIN.midnight().checked_sub_years(1), None);
+    /// ```
+    pub const fn checked_sub_years(self, years: i32) -> Option<Self> {
+        Some(Self {
+            date: const_try_opt!(self.date.checked_sub_years(years)),
+            time: self.time,
+        })
+    }
+
+    /// Computes `self` shifted by the provided number of calendar days, returning `None` if the
+    /// resulting year is out of range. Only the date is changed; the time is always unchanged.
+    ///
+    /// ```
+    /// # use time::{Date, macros::datetime};
+    /// assert_eq!(
+    ///     datetime!(2019 - 12 - 31 15:30:45.5).checked_add_days(1),
+    ///     Some(datetime!(2020 - 01 - 01 15:30:45.5))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2020 - 03 - 01 0:00).checked_add_days(-1),
+    ///     Some(datetime!(2020 - 02 - 29 0:00))
+    /// );
+    /// assert_eq!(Date::MAX.midnight().checked_add_days(1), None);
+    /// assert_eq!(Date::MIN.midnight().checked_add_days(i64::MIN), None);
+    /// ```
+    pub const fn checked_add_days(self, days: i64) -> Option<Self> {
+        let julian_day = const_try_opt!((self.date.to_julian_day() as i64).checked_add(days));
+        if julian_day < i32::MIN as i64 || julian_day > i32::MAX as i64 {
+            return None;
+        }
+
+        match Date::from_julian_day(julian_day as _) {
+            Ok(date) => Some(Self {
+                date,
+                time: self.time,
+            }),
+            Err(_) => None,
+        }
+    }
+
+    /// Computes `self` shifted by the provided number of calendar years, months, and days,
+    /// returning `None` if the resulting year is out of range. The time is unchanged.
+    ///
+    /// The order of operations matters. This is equivalent to calling
+    /// [`checked_add_years`](Self::checked_add_years), then
+    /// [`checked_add_months`](Self::checked_add_months), then
+    /// [`checked_add_days`](Self::checked_add_days). The day saturates after each of the first two
+    /// steps, and every intermediate value must be in range.
+    ///
+    /// ```
+    /// # use time::{Date, macros::datetime};
+    /// assert_eq!(
+    ///     datetime!(2020 - 01 - 31 15:30).checked_add_calendar(1, 2, 10),
+    ///     Some(datetime!(2021 - 04 - 10 15:30))
+    /// );
+    /// // Applying the days first would give a different result.
+    /// assert_eq!(
+    ///     datetime!(2020 - 01 - 30 15:30).checked_add_calendar(0, 1, 1),
+    ///     Some(datetime!(2020 - 03 - 01 15:30))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2020 - 01 - 30 15:30)
+    ///         .checked_add_days(1)
+    ///         .and_then(|datetime| datetime.checked_add_months(1)),
+    ///     Some(datetime!(2020 - 02 - 29 15:30))
+    /// );
+    /// // The years are applied before the months, so the day is clamped to February 28 first.
+    /// assert_eq!(
+    ///     datetime!(2020 - 02 - 29 15:30).checked_add_calendar(1, 1, 0),
+    ///     Some(datetime!(2021 - 03 - 28 15:30))
+    /// );
+    /// assert_eq!(Date::MAX.midnight().checked_add_calendar(0, 0, 1), None);
+    /// assert_eq!(Date::MAX.midnight().checked_add_calendar(1, -12, 0), None);
+    /// ```
+    pub const fn checked_add_calendar(self, years: i32, months: i32, days: i64) -> Option<Self> {
+        let datetime = const_try_opt!(self.checked_add_years(years));
+        let datetime = const_try_opt!(datetime.checked_add_months(months));
+        datetime.checked_add_days(days)
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -578,6 +867,98 @@ impl PrimitiveDateTime {
         }
     }
     // endregion: saturating arithmetic
+
+    // region: truncation
+    /// Truncate the `PrimitiveDateTime` to the second, setting the subsecond component to zero.
+    /// The date is unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789_012_345).truncate_to_second(),
+    ///     datetime!(2019-01-01 12:34:56)
+    /// );
+    /// ```
+    pub const fn truncate_to_second(self) -> Self {
+        Self {
+            date: self.date,
+            time: self.time.truncate_to_second(),
+        }
+    }
+
+    /// Truncate the `PrimitiveDateTime` to the millisecond, setting the digits beyond the
+    /// millisecond to zero. The date is unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789_012_345).truncate_to_millisecond(),
+    ///     datetime!(2019-01-01 12:34:56.789)
+    /// );
+    /// ```
+    pub const fn truncate_to_millisecond(self) -> Self {
+        Self {
+            date: self.date,
+            time: self.time.truncate_to_millisecond(),
+        }
+    }
+
+    /// Truncate the `PrimitiveDateTime` to the microsecond, setting the digits beyond the
+    /// microsecond to zero. The date is unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789_012_345).truncate_to_microsecond(),
+    ///     datetime!(2019-01-01 12:34:56.789_012)
+    /// );
+    /// ```
+    pub const fn truncate_to_microsecond(self) -> Self {
+        Self {
+            date: self.date,
+            time: self.time.truncate_to_microsecond(),
+        }
+    }
+    // endregion truncation
+
+    // region: comparison
+    /// Compare two `PrimitiveDateTime`s. This is equivalent to [`Ord::cmp`], but is usable in
+    /// `const` contexts.
+    ///
+    /// ```rust
+    /// # use core::cmp::Ordering;
+    /// # use time::macros::datetime;
+    /// const ORDERING: Ordering = datetime!(2019-01-01 0:00).compare(datetime!(2019-01-01 0:01));
+    /// assert_eq!(ORDERING, Ordering::Less);
+    /// assert_eq!(
+    ///     datetime!(2019-01-02 0:00).compare(datetime!(2019-01-01 23:59)),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00).compare(datetime!(2019-01-01 0:00)),
+    ///     Ordering::Equal
+    /// );
+    /// ```
+    pub const fn compare(self, other: Self) -> Ordering {
+        /// Compare two values of the same integer type.
+        macro_rules! compare {
+            ($a:expr, $b:expr) => {
+                if $a < $b {
+                    return Ordering::Less;
+                } else if $a > $b {
+                    return Ordering::Greater;
+                }
+            };
+        }
+
+        compare!(self.date.value, other.date.value);
+        compare!(self.hour(), other.hour());
+        compare!(self.minute(), other.minute());
+        compare!(self.second(), other.second());
+        compare!(self.nanosecond(), other.nanosecond());
+        Ordering::Equal
+    }
+    // endregion comparison
 }
 
 // region: replacement
@@ -610,6 +991,99 @@ impl PrimitiveDateTime {
     pub const fn replace_date(self, date: Date) -> Self {
         date.with_time(self.time)
     }
+
+    /// Replace both the date and the time.
+    ///
+    /// ```rust
+    /// # use time::macros::{datetime, date, time};
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 12:00).replace_date_time(date!(2020-01-30), time!(5:00)),
+    ///     datetime!(2020-01-30 5:00)
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `PrimitiveDateTime`."]
+    pub const fn replace_date_time(self, date: Date, time: Time) -> Self {
+        date.with_time(time)
+    }
+
+    /// Attempt to replace the time with the provided components, preserving the date.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 17:00).with_hms(5, 6, 7),
+    ///     Ok(datetime!(2020-01-01 5:06:07))
+    /// );
+    /// assert!(datetime!(2020-01-01 17:00).with_hms(24, 0, 0).is_err());
+    /// ```
+    pub const fn with_hms(
+        self,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        self.date.with_hms(hour, minute, second)
+    }
+
+    /// Attempt to replace the time with the provided components, preserving the date.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 17:00).with_hms_milli(5, 6, 7, 8),
+    ///     Ok(datetime!(2020-01-01 5:06:07.008))
+    /// );
+    /// assert!(datetime!(2020-01-01 17:00).with_hms_milli(0, 0, 0, 1_000).is_err());
+    /// ```
+    pub const fn with_hms_milli(
+        self,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+    ) -> Result<Self, error::ComponentRange> {
+        self.date.with_hms_milli(hour, minute, second, millisecond)
+    }
+
+    /// Attempt to replace the time with the provided components, preserving the date.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 17:00).with_hms_micro(5, 6, 7, 8),
+    ///     Ok(datetime!(2020-01-01 5:06:07.000_008))
+    /// );
+    /// assert!(datetime!(2020-01-01 17:00).with_hms_micro(0, 0, 0, 1_000_000).is_err());
+    /// ```
+    pub const fn with_hms_micro(
+        self,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        microsecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        self.date.with_hms_micro(hour, minute, second, microsecond)
+    }
+
+    /// Attempt to replace the time with the provided components, preserving the date.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 17:00).with_hms_nano(5, 6, 7, 8),
+    ///     Ok(datetime!(2020-01-01 5:06:07.000_000_008))
+    /// );
+    /// assert!(datetime!(2020-01-01 17:00).with_hms_nano(0, 0, 0, 1_000_000_000).is_err());
+    /// ```
+    pub const fn with_hms_nano(
+        self,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        self.date.with_hms_nano(hour, minute, second, nanosecond)
+    }
 }
 // endregion replacement
 
@@ -656,7 +1130,19 @@ impl PrimitiveDateTime {
 #[cfg(feature = "formatting")]
 impl PrimitiveDateTime {
     /// Format the `PrimitiveDateTime` using the provided [format
-    /// description](crate::format_description).
+    /// description](crate::format_description), writing the result directly into `output`. The
+    /// number of bytes written is returned.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]")?;
+    /// let mut buf = Vec::new();
+    /// assert_eq!(datetime!(2020-01-02 03:04).format_into(&mut buf, &format)?, 16);
+    /// assert_eq!(buf, b"2020-01-02 03:04");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -665,6 +1151,34 @@ impl PrimitiveDateTime {
         format.format_into(output, Some(self.date), Some(self.time), None)
     }
 
+    /// Format the `PrimitiveDateTime` using the provided [format
+    /// description](crate::format_description), writing the result into a [`fmt::Write`]
+    /// implementor such as a `String`.
+    ///
+    /// Unlike [`format_into`](Self::format_into), this does not require the `std` feature.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]")?;
+    /// let mut buf = String::new();
+    /// datetime!(2020-01-02 03:04).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "2020-01-02 03:04");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<(), error::Format> {
+        format.format_into(
+            &mut FmtWriter(output),
+            Some(self.date),
+            Some(self.time),
+            None,
+        )?;
+        Ok(())
+    }
+
     /// Format the `PrimitiveDateTime` using the provided [format
     /// description](crate::format_description).
     ///
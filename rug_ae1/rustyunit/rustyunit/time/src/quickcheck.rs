@@ -82,17 +82,25 @@ impl Arbitrary for Duration {
     }
 
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let components = (self.subsec_nanoseconds(), self.whole_seconds())
+            .shrink()
+            .map(|(mut nanoseconds, seconds)| {
+                // Coerce the sign if necessary.
+                if (seconds > 0 && nanoseconds < 0) || (seconds < 0 && nanoseconds > 0) {
+                    nanoseconds *= -1;
+                }
+
+                Self::new_unchecked(seconds, nanoseconds)
+            });
+
+        // Shrinking the total first tries zero and the sign flip, then values between zero and the
+        // original. Only afterwards is each component shrunk independently. Candidates that are out
+        // of range, such as the sign flip of `Duration::MIN`, are skipped.
         Box::new(
-            (self.subsec_nanoseconds(), self.whole_seconds())
+            self.whole_nanoseconds()
                 .shrink()
-                .map(|(mut nanoseconds, seconds)| {
-                    // Coerce the sign if necessary.
-                    if (seconds > 0 && nanoseconds < 0) || (seconds < 0 && nanoseconds > 0) {
-                        nanoseconds *= -1;
-                    }
-
-                    Self::new_unchecked(seconds, nanoseconds)
-                }),
+                .filter_map(Self::checked_nanoseconds_i128)
+                .chain(components),
         )
     }
 }
@@ -1,10 +1,70 @@
 //! Implementation of [`Distribution`] for various structs.
 
+use core::ops::RangeInclusive;
+
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
+/// A [`Distribution`] that samples [`Date`]s uniformly from an inclusive range. Every day in the
+/// range is equally likely.
+///
+/// # Panics
+///
+/// Sampling panics if the range is empty.
+///
+/// ```rust
+/// # use rand::{rngs::mock::StepRng, Rng};
+/// # use time::{macros::date, rand::DateRange};
+/// let mut rng = StepRng::new(0, 1 << 58);
+/// let range = date!(2020 - 01 - 01)..=date!(2020 - 12 - 31);
+/// for _ in 0..100 {
+///     assert!(range.contains(&rng.sample(DateRange(range.clone()))));
+/// }
+///
+/// // A range containing a single day always yields that day.
+/// let day = date!(2020 - 02 - 29);
+/// assert_eq!(rng.sample(DateRange(day..=day)), day);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateRange(pub RangeInclusive<Date>);
+
+impl Distribution<Date> for DateRange {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Date {
+        Date::from_julian_day_unchecked(
+            rng.gen_range(self.0.start().to_julian_day()..=self.0.end().to_julian_day()),
+        )
+    }
+}
+
+/// A [`Distribution`] that samples [`UtcOffset`]s resembling those used by real-world time zones.
+/// Offsets are sampled uniformly from `-12:00..=+14:00` with a granularity of 15 minutes.
+///
+/// This distribution is realistic, not exhaustive. Use [`Standard`] to sample from every
+/// representable offset.
+///
+/// ```rust
+/// # use rand::{rngs::mock::StepRng, Rng};
+/// # use time::{UtcOffset, rand::UtcOffsetWithinRealWorldBounds};
+/// let mut rng = StepRng::new(0, 1 << 58);
+/// for _ in 0..100 {
+///     let offset: UtcOffset = rng.sample(UtcOffsetWithinRealWorldBounds);
+///     assert!((-12..=14).contains(&offset.whole_hours()));
+///     assert_eq!(offset.minutes_past_hour() % 15, 0);
+///     assert_eq!(offset.seconds_past_minute(), 0);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtcOffsetWithinRealWorldBounds;
+
+impl Distribution<UtcOffset> for UtcOffsetWithinRealWorldBounds {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UtcOffset {
+        let minutes = rng.gen_range(-48..=56) * 15;
+        UtcOffset::__from_hms_unchecked((minutes / 60) as _, (minutes % 60) as _, 0)
+    }
+}
+
 impl Distribution<Time> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Time {
         Time::__from_hms_nanos_unchecked(
@@ -0,0 +1,78 @@
+//! Treat an [`Instant`] as a [`Duration`] relative to the current instant for the purposes of
+//! serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! When serializing, the value is the signed duration from [`Instant::now`] to the instant. When
+//! deserializing, that duration is added to the value of [`Instant::now`] at that time. Any time
+//! that passes between serializing and deserializing is therefore lost.
+//!
+//! **The deserialized value is only meaningful within the same run of the same process.**
+//! Instants are measured against an unspecified, platform-dependent reference point that is not
+//! preserved across process boundaries or reboots. This module is suitable for
+//! snapshotting and restoring state in memory, not for persistent storage or for sending values
+//! to another process.
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Duration, Instant};
+
+/// Serialize an `Instant` as the [`Duration`] relative to the current instant
+pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+    instant
+        .checked_signed_duration_since(Instant::now())
+        .ok_or_else(|| ser::Error::custom("`Instant` is too far from the current instant"))?
+        .serialize(serializer)
+}
+
+/// Deserialize an `Instant` from the [`Duration`] relative to the current instant
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Instant, D::Error> {
+    Instant::now()
+        .checked_add(Duration::deserialize(deserializer)?)
+        .ok_or_else(|| de::Error::custom("`Instant` is out of range"))
+}
+
+/// Treat an `Option<Instant>` as a [`Duration`] relative to the current instant for the purposes
+/// of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// **The deserialized value is only meaningful within the same run of the same process.** See
+/// the [parent module](super) for details.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize an `Option<Instant>` as the [`Duration`] relative to the current instant
+    pub fn serialize<S: Serializer>(
+        option: &Option<Instant>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let now = Instant::now();
+        option
+            .map(|instant| {
+                instant.checked_signed_duration_since(now).ok_or_else(|| {
+                    ser::Error::custom("`Instant` is too far from the current instant")
+                })
+            })
+            .transpose()?
+            .serialize(serializer)
+    }
+
+    /// Deserialize an `Option<Instant>` from the [`Duration`] relative to the current instant
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Instant>, D::Error> {
+        let now = Instant::now();
+        Option::<Duration>::deserialize(deserializer)?
+            .map(|duration| {
+                now.checked_add(duration)
+                    .ok_or_else(|| de::Error::custom("`Instant` is out of range"))
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,192 @@
+//! Use the [ISO 8601 format] when serializing and deserializing a [`Date`](crate::Date) or
+//! [`Time`](crate::Time).
+//!
+//! Use the submodules in combination with serde's [`#[with]`][with] attribute. Human-readable
+//! serializers store the value as an ISO 8601 string, while all others use the same compact
+//! representation as the type's own `Serialize` implementation.
+//!
+//! [ISO 8601 format]: https://www.iso.org/iso-8601-date-and-time-format.html
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use crate::format_description::{modifier, Component, FormatItem};
+
+/// Marker type for the ISO 8601 representation of a [`Date`](crate::Date).
+pub(super) enum IsoDate {}
+
+/// Marker type for the ISO 8601 representation of a [`Time`](crate::Time).
+pub(super) enum IsoTime {}
+
+/// The format of an ISO 8601 time of day without a fractional second, `hh:mm:ss`.
+const WHOLE_SECOND_TIME_FORMAT: &[FormatItem<'_>] = &[
+    FormatItem::Component(Component::Hour(<modifier::Hour>::default())),
+    FormatItem::Literal(b":"),
+    FormatItem::Component(Component::Minute(<modifier::Minute>::default())),
+    FormatItem::Literal(b":"),
+    FormatItem::Component(Component::Second(<modifier::Second>::default())),
+];
+
+/// The format of an ISO 8601 time of day, `hh:mm:ss[.s+]`. Either a period or a comma may be used
+/// as the decimal sign when parsing.
+pub(super) const TIME_FORMAT: &[FormatItem<'_>] = &[
+    FormatItem::Compound(WHOLE_SECOND_TIME_FORMAT),
+    FormatItem::Optional(&FormatItem::Compound(&[
+        FormatItem::First(&[FormatItem::Literal(b"."), FormatItem::Literal(b",")]),
+        FormatItem::Component(Component::Subsecond(modifier::Subsecond {
+            digits: modifier::SubsecondDigits::OneOrMore,
+        })),
+    ])),
+];
+
+/// Use the ISO 8601 calendar date format, `YYYY-MM-DD`, when serializing and deserializing a
+/// [`Date`].
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod date {
+    use core::marker::PhantomData;
+
+    use serde::ser::Error as _;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    use super::IsoDate;
+    use crate::serde::visitor::Visitor;
+    use crate::serde::DATE_FORMAT;
+    use crate::Date;
+
+    /// Serialize a [`Date`] using the ISO 8601 calendar date format.
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return date.serialize(serializer);
+        }
+
+        date.format(&DATE_FORMAT)
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserialize a [`Date`] from its ISO 8601 representation.
+    pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Date, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Visitor::<IsoDate>(PhantomData))
+        } else {
+            deserializer.deserialize_tuple(2, Visitor::<Date>(PhantomData))
+        }
+    }
+
+    /// Use the ISO 8601 calendar date format when serializing and deserializing an
+    /// [`Option<Date>`].
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [with]: https://serde.rs/field-attrs.html#with
+    pub mod option {
+        #[allow(clippy::wildcard_imports)]
+        use super::*;
+
+        /// Serialize an [`Option<Date>`] using the ISO 8601 calendar date format.
+        pub fn serialize<S: Serializer>(
+            option: &Option<Date>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if !serializer.is_human_readable() {
+                return option.serialize(serializer);
+            }
+
+            option
+                .map(|date| date.format(&DATE_FORMAT))
+                .transpose()
+                .map_err(S::Error::custom)?
+                .serialize(serializer)
+        }
+
+        /// Deserialize an [`Option<Date>`] from its ISO 8601 representation.
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Option<Date>, D::Error> {
+            deserializer.deserialize_option(Visitor::<Option<IsoDate>>(PhantomData))
+        }
+    }
+}
+
+/// Use the ISO 8601 time of day format, `hh:mm:ss[.s+]`, when serializing and deserializing a
+/// [`Time`]. The fractional second is only present when it is nonzero, and is written with as few
+/// digits as possible without losing precision.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod time {
+    use core::marker::PhantomData;
+
+    use serde::ser::Error as _;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    use super::{IsoTime, TIME_FORMAT, WHOLE_SECOND_TIME_FORMAT};
+    use crate::error;
+    use crate::serde::visitor::Visitor;
+    use crate::Time;
+
+    /// Format a [`Time`], omitting the fractional second when it is zero.
+    fn format(time: Time) -> Result<String, error::Format> {
+        if time.nanosecond() == 0 {
+            time.format(&WHOLE_SECOND_TIME_FORMAT)
+        } else {
+            time.format(&TIME_FORMAT)
+        }
+    }
+
+    /// Serialize a [`Time`] using the ISO 8601 time of day format.
+    pub fn serialize<S: Serializer>(time: &Time, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return time.serialize(serializer);
+        }
+
+        format(*time)
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserialize a [`Time`] from its ISO 8601 representation.
+    pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Time, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Visitor::<IsoTime>(PhantomData))
+        } else {
+            deserializer.deserialize_tuple(4, Visitor::<Time>(PhantomData))
+        }
+    }
+
+    /// Use the ISO 8601 time of day format when serializing and deserializing an
+    /// [`Option<Time>`].
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [with]: https://serde.rs/field-attrs.html#with
+    pub mod option {
+        #[allow(clippy::wildcard_imports)]
+        use super::*;
+
+        /// Serialize an [`Option<Time>`] using the ISO 8601 time of day format.
+        pub fn serialize<S: Serializer>(
+            option: &Option<Time>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if !serializer.is_human_readable() {
+                return option.serialize(serializer);
+            }
+
+            option
+                .map(format)
+                .transpose()
+                .map_err(S::Error::custom)?
+                .serialize(serializer)
+        }
+
+        /// Deserialize an [`Option<Time>`] from its ISO 8601 representation.
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Option<Time>, D::Error> {
+            deserializer.deserialize_option(Visitor::<Option<IsoTime>>(PhantomData))
+        }
+    }
+}
@@ -13,6 +13,11 @@ macro_rules! item {
     };
 }
 
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+pub mod instant_relative;
+#[cfg(feature = "serde-well-known")]
+pub mod iso8601;
 #[cfg(feature = "serde-well-known")]
 pub mod rfc2822;
 #[cfg(feature = "serde-well-known")]
@@ -7,6 +7,8 @@ use serde::de;
 #[cfg(feature = "serde-well-known")]
 use serde::Deserializer;
 
+#[cfg(feature = "serde-well-known")]
+use super::iso8601::{self, IsoDate, IsoTime};
 #[cfg(feature = "parsing")]
 use super::{
     DATE_FORMAT, OFFSET_DATE_TIME_FORMAT, PRIMITIVE_DATE_TIME_FORMAT, TIME_FORMAT,
@@ -327,3 +329,63 @@ impl<'a> de::Visitor<'a> for Visitor<Option<well_known::Rfc3339>> {
         Ok(None)
     }
 }
+
+#[cfg(feature = "serde-well-known")]
+impl<'a> de::Visitor<'a> for Visitor<IsoDate> {
+    type Value = Date;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601-formatted `Date`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Date, E> {
+        Date::parse(value, &DATE_FORMAT).map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "serde-well-known")]
+impl<'a> de::Visitor<'a> for Visitor<Option<IsoDate>> {
+    type Value = Option<Date>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601-formatted `Option<Date>`")
+    }
+
+    fn visit_some<D: Deserializer<'a>>(self, deserializer: D) -> Result<Option<Date>, D::Error> {
+        iso8601::date::deserialize(deserializer).map(Some)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<Date>, E> {
+        Ok(None)
+    }
+}
+
+#[cfg(feature = "serde-well-known")]
+impl<'a> de::Visitor<'a> for Visitor<IsoTime> {
+    type Value = Time;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601-formatted `Time`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Time, E> {
+        Time::parse(value, &iso8601::TIME_FORMAT).map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "serde-well-known")]
+impl<'a> de::Visitor<'a> for Visitor<Option<IsoTime>> {
+    type Value = Option<Time>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601-formatted `Option<Time>`")
+    }
+
+    fn visit_some<D: Deserializer<'a>>(self, deserializer: D) -> Result<Option<Time>, D::Error> {
+        iso8601::time::deserialize(deserializer).map(Some)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<Time>, E> {
+        Ok(None)
+    }
+}
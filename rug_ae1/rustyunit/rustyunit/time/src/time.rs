@@ -1,13 +1,15 @@
 //! The [`Time`] struct and its associated `impl`s.
 
+#[cfg(feature = "formatting")]
+use alloc::string::String;
 use core::fmt;
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::{FmtWriter, Formattable};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::util::DateAdjustment;
@@ -103,6 +105,20 @@ impl Time {
     /// assert!(Time::from_hms(0, 60, 0).is_err()); // 60 isn't a valid minute.
     /// assert!(Time::from_hms(0, 0, 60).is_err()); // 60 isn't a valid second.
     /// ```
+    ///
+    /// This method, along with [`Time::from_hms_milli`], [`Time::from_hms_micro`], and
+    /// [`Time::from_hms_nano`], is `const`. The result can be matched on in a constant to avoid
+    /// the need for the [`time!`](crate::macros::time) macro.
+    ///
+    /// ```rust
+    /// # use time::{error, Time};
+    /// const NOON: Result<Time, error::ComponentRange> = Time::from_hms(12, 0, 0);
+    /// const NOON_HOUR: u8 = match NOON {
+    ///     Ok(time) => time.hour(),
+    ///     Err(_) => 0,
+    /// };
+    /// assert_eq!(NOON_HOUR, 12);
+    /// ```
     pub const fn from_hms(hour: u8, minute: u8, second: u8) -> Result<Self, error::ComponentRange> {
         ensure_value_in_range!(hour in 0 => 23);
         ensure_value_in_range!(minute in 0 => 59);
@@ -202,6 +218,95 @@ impl Time {
             hour, minute, second, nanosecond,
         ))
     }
+
+    /// Attempt to create a `Time` from the number of seconds since midnight, represented as `f64`.
+    /// The value is rounded to the nearest nanosecond.
+    ///
+    /// ```rust
+    /// # use time::{Time, macros::time};
+    /// assert_eq!(Time::from_seconds_since_midnight_f64(0.)?, time!(0:00));
+    /// assert_eq!(
+    ///     Time::from_seconds_since_midnight_f64(3_723.5)?,
+    ///     time!(1:02:03.5)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// assert!(Time::from_seconds_since_midnight_f64(-1.).is_err());
+    /// assert!(Time::from_seconds_since_midnight_f64(86_400.).is_err());
+    /// assert!(Time::from_seconds_since_midnight_f64(f64::NAN).is_err());
+    /// assert!(Time::from_seconds_since_midnight_f64(f64::INFINITY).is_err());
+    /// ```
+    pub fn from_seconds_since_midnight_f64(seconds: f64) -> Result<Self, error::ComponentRange> {
+        let error = error::ComponentRange {
+            name: "seconds",
+            minimum: 0,
+            maximum: 86_399,
+            value: seconds as _,
+            conditional_range: false,
+        };
+
+        // `NaN` is not contained in any range, so it is rejected here as well.
+        if !(0. ..86_400.).contains(&seconds) {
+            return Err(error);
+        }
+
+        let nanoseconds = (seconds * 1_000_000_000. + 0.5) as u64;
+        if nanoseconds >= 86_400_000_000_000 {
+            return Err(error);
+        }
+
+        Ok(Self::__from_hms_nanos_unchecked(
+            (nanoseconds / 3_600_000_000_000) as _,
+            (nanoseconds / 60_000_000_000 % 60) as _,
+            (nanoseconds / 1_000_000_000 % 60) as _,
+            (nanoseconds % 1_000_000_000) as _,
+        ))
+    }
+
+    /// Create a `Time` from the [`Duration`] that has elapsed since midnight. The duration must be
+    /// nonnegative and less than 24 hours.
+    ///
+    /// ```rust
+    /// # use time::{Duration, Time, macros::time};
+    /// assert_eq!(
+    ///     Time::from_duration_since_midnight(Duration::ZERO)?,
+    ///     time!(0:00)
+    /// );
+    /// assert_eq!(
+    ///     Time::from_duration_since_midnight(Duration::new(86_399, 999_999_999))?,
+    ///     time!(23:59:59.999_999_999)
+    /// );
+    /// assert!(Time::from_duration_since_midnight(Duration::DAY).is_err());
+    /// assert!(Time::from_duration_since_midnight(-Duration::NANOSECOND).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn from_duration_since_midnight(
+        duration: Duration,
+    ) -> Result<Self, error::ComponentRange> {
+        let nanoseconds = duration.whole_nanoseconds();
+        if nanoseconds < 0 || nanoseconds > 86_399_999_999_999 {
+            return Err(error::ComponentRange {
+                name: "nanoseconds",
+                minimum: 0,
+                maximum: 86_399_999_999_999,
+                value: if nanoseconds < i64::MIN as i128 {
+                    i64::MIN
+                } else if nanoseconds > i64::MAX as i128 {
+                    i64::MAX
+                } else {
+                    nanoseconds as _
+                },
+                conditional_range: false,
+            });
+        }
+
+        Ok(Self::from_nanoseconds_since_midnight_unchecked(
+            nanoseconds as _,
+        ))
+    }
     // endregion constructors
 
     // region: getters
@@ -337,8 +442,382 @@ impl Time {
     pub const fn nanosecond(self) -> u32 {
         self.nanosecond
     }
+
+    /// Get the fraction of the day that has elapsed.
+    ///
+    /// The returned value will always be in the range `0.0..1.0`.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(time!(0:00).day_fraction(), 0.);
+    /// assert_eq!(time!(6:00).day_fraction(), 0.25);
+    /// assert_eq!(time!(12:00).day_fraction(), 0.5);
+    /// assert!(time!(23:59:59.999_999_999).day_fraction() < 1.);
+    /// ```
+    pub fn day_fraction(self) -> f64 {
+        self.nanoseconds_since_midnight() as f64 / 86_400_000_000_000.
+    }
+
+    /// Get the [`Duration`] that has elapsed since midnight. This is the inverse of
+    /// [`Time::from_duration_since_midnight`].
+    ///
+    /// ```rust
+    /// # use time::{Duration, macros::time};
+    /// assert_eq!(time!(0:00).duration_since_midnight(), Duration::ZERO);
+    /// assert_eq!(
+    ///     time!(1:02:03.004).duration_since_midnight(),
+    ///     Duration::new(3_723, 4_000_000)
+    /// );
+    /// ```
+    pub const fn duration_since_midnight(self) -> Duration {
+        Duration::new_unchecked(
+            self.hour as i64 * 3_600 + self.minute as i64 * 60 + self.second as i64,
+            self.nanosecond as _,
+        )
+    }
     // endregion getters
 
+    // region: nanoseconds since midnight
+    /// Get the number of nanoseconds since midnight. The returned value will always be in the
+    /// range `0..86_400_000_000_000`, and ordering of the returned values matches ordering of the
+    /// times.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(time!(0:00).nanoseconds_since_midnight(), 0);
+    /// assert_eq!(time!(1:00:00.5).nanoseconds_since_midnight(), 3_600_500_000_000);
+    /// assert_eq!(
+    ///     time!(23:59:59.999_999_999).nanoseconds_since_midnight(),
+    ///     86_399_999_999_999
+    /// );
+    /// assert!(
+    ///     time!(12:59:59.999_999_999).nanoseconds_since_midnight()
+    ///         < time!(13:00).nanoseconds_since_midnight()
+    /// );
+    /// ```
+    pub const fn nanoseconds_since_midnight(self) -> u64 {
+        self.hour as u64 * 3_600_000_000_000
+            + self.minute as u64 * 60_000_000_000
+            + self.second as u64 * 1_000_000_000
+            + self.nanosecond as u64
+    }
+
+    /// Create a `Time` from the number of nanoseconds since midnight. This is the inverse of
+    /// [`Time::nanoseconds_since_midnight`]. Values of a full day or more are rejected.
+    ///
+    /// ```rust
+    /// # use time::{Time, macros::time};
+    /// assert_eq!(
+    ///     Time::from_nanoseconds_since_midnight(3_600_500_000_000),
+    ///     Ok(time!(1:00:00.5))
+    /// );
+    /// assert_eq!(
+    ///     Time::from_nanoseconds_since_midnight(
+    ///         time!(23:59:59.999_999_999).nanoseconds_since_midnight()
+    ///     ),
+    ///     Ok(time!(23:59:59.999_999_999))
+    /// );
+    /// assert!(Time::from_nanoseconds_since_midnight(86_400_000_000_000).is_err());
+    /// ```
+    pub const fn from_nanoseconds_since_midnight(
+        nanoseconds: u64,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(nanoseconds in 0 => 86_399_999_999_999);
+        Ok(Self::from_nanoseconds_since_midnight_unchecked(nanoseconds))
+    }
+
+    /// Create a `Time` from the number of nanoseconds since midnight, the validity of which must be
+    /// guaranteed by the caller.
+    const fn from_nanoseconds_since_midnight_unchecked(nanoseconds: u64) -> Self {
+        Self::__from_hms_nanos_unchecked(
+            (nanoseconds / 3_600_000_000_000) as _,
+            (nanoseconds / 60_000_000_000 % 60) as _,
+            (nanoseconds / 1_000_000_000 % 60) as _,
+            (nanoseconds % 1_000_000_000) as _,
+        )
+    }
+    // endregion nanoseconds since midnight
+
+    // region: rounding
+    /// Round the `Time` to the nearest multiple of `interval` since midnight, returning whether
+    /// rounding wrapped past midnight to the next day. A value exactly halfway between two
+    /// multiples is rounded up.
+    ///
+    /// If `interval` is not positive or does not evenly divide a day, the value is returned
+    /// unchanged.
+    ///
+    /// ```rust
+    /// # use time::{Duration, macros::time};
+    /// assert_eq!(
+    ///     time!(12:07:30).round_to_nearest(Duration::minutes(15)),
+    ///     (false, time!(12:15))
+    /// );
+    /// assert_eq!(
+    ///     time!(12:07:29).round_to_nearest(Duration::minutes(15)),
+    ///     (false, time!(12:00))
+    /// );
+    /// assert_eq!(
+    ///     time!(23:59:58).round_to_nearest(Duration::seconds(5)),
+    ///     (true, time!(0:00))
+    /// );
+    /// assert_eq!(
+    ///     time!(12:07:30).round_to_nearest(Duration::minutes(7)),
+    ///     (false, time!(12:07:30))
+    /// );
+    /// ```
+    pub const fn round_to_nearest(self, interval: Duration) -> (bool, Self) {
+        /// The number of nanoseconds in a day.
+        const NANOSECONDS_PER_DAY: u64 = 86_400_000_000_000;
+
+        let interval = interval.whole_nanoseconds();
+        if interval <= 0 || NANOSECONDS_PER_DAY as i128 % interval != 0 {
+            return (false, self);
+        }
+        let interval = interval as u64;
+
+        let rounded = (self.nanoseconds_since_midnight() + interval / 2) / interval * interval;
+        if rounded == NANOSECONDS_PER_DAY {
+            (true, Self::MIDNIGHT)
+        } else {
+            (
+                false,
+                Self::from_nanoseconds_since_midnight_unchecked(rounded),
+            )
+        }
+    }
+
+    /// Get the time exactly halfway between `self` and `other`. Both values are treated as points
+    /// in the range `00:00..24:00` of the same day, so the result is always between the two and
+    /// never wraps around midnight. If the midpoint is not a whole nanosecond, it is rounded down.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(time!(6:00).midpoint(time!(18:00)), time!(12:00));
+    /// assert_eq!(time!(18:00).midpoint(time!(6:00)), time!(12:00));
+    /// assert_eq!(time!(23:00).midpoint(time!(1:00)), time!(12:00));
+    /// assert_eq!(
+    ///     time!(0:00).midpoint(time!(0:00:00.000_000_001)),
+    ///     time!(0:00)
+    /// );
+    /// ```
+    pub const fn midpoint(self, other: Self) -> Self {
+        Self::from_nanoseconds_since_midnight_unchecked(
+            (self.nanoseconds_since_midnight() + other.nanoseconds_since_midnight()) / 2,
+        )
+    }
+
+    /// Truncate the `Time` to the second, setting the subsecond component to zero.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(12:34:56.789_012_345).truncate_to_second(),
+    ///     time!(12:34:56)
+    /// );
+    /// ```
+    pub const fn truncate_to_second(self) -> Self {
+        Self::__from_hms_nanos_unchecked(self.hour, self.minute, self.second, 0)
+    }
+
+    /// Truncate the `Time` to the millisecond, setting the digits beyond the millisecond to zero.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(12:34:56.789_012_345).truncate_to_millisecond(),
+    ///     time!(12:34:56.789)
+    /// );
+    /// ```
+    pub const fn truncate_to_millisecond(self) -> Self {
+        Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond - self.nanosecond % 1_000_000,
+        )
+    }
+
+    /// Truncate the `Time` to the microsecond, setting the digits beyond the microsecond to zero.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(12:34:56.789_012_345).truncate_to_microsecond(),
+    ///     time!(12:34:56.789_012)
+    /// );
+    /// ```
+    pub const fn truncate_to_microsecond(self) -> Self {
+        Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond - self.nanosecond % 1_000,
+        )
+    }
+    // endregion rounding
+
+    // region: replacement
+    /// Replace the clock hour.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(01:02:03.004_005_006).replace_hour(7),
+    ///     Ok(time!(07:02:03.004_005_006))
+    /// );
+    /// assert!(time!(01:02:03.004_005_006).replace_hour(24).is_err()); // 24 isn't a valid hour
+    /// ```
+    pub const fn replace_hour(self, hour: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(hour in 0 => 23);
+        Ok(Self::__from_hms_nanos_unchecked(
+            hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+        ))
+    }
+
+    /// Replace the minutes within the hour.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(01:02:03.004_005_006).replace_minute(7),
+    ///     Ok(time!(01:07:03.004_005_006))
+    /// );
+    /// assert!(time!(01:02:03.004_005_006).replace_minute(60).is_err()); // 60 isn't a valid minute
+    /// ```
+    pub const fn replace_minute(self, minute: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(minute in 0 => 59);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            minute,
+            self.second,
+            self.nanosecond,
+        ))
+    }
+
+    /// Replace the seconds within the minute.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(01:02:03.004_005_006).replace_second(7),
+    ///     Ok(time!(01:02:07.004_005_006))
+    /// );
+    /// assert!(time!(01:02:03.004_005_006).replace_second(60).is_err()); // 60 isn't a valid second
+    /// ```
+    pub const fn replace_second(self, second: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(second in 0 => 59);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            second,
+            self.nanosecond,
+        ))
+    }
+
+    /// Replace the milliseconds within the second. Any sub-millisecond precision is discarded.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(01:02:03.004_005_006).replace_millisecond(7),
+    ///     Ok(time!(01:02:03.007))
+    /// );
+    /// assert!(time!(01:02:03.004_005_006).replace_millisecond(1_000).is_err()); // 1_000 isn't a valid millisecond
+    /// ```
+    pub const fn replace_millisecond(
+        self,
+        millisecond: u16,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(millisecond in 0 => 999);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            self.second,
+            millisecond as u32 * 1_000_000,
+        ))
+    }
+
+    /// Replace the microseconds within the second. Any sub-microsecond precision is discarded.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(01:02:03.004_005_006).replace_microsecond(7_008),
+    ///     Ok(time!(01:02:03.007_008))
+    /// );
+    /// assert!(time!(01:02:03.004_005_006).replace_microsecond(1_000_000).is_err()); // 1_000_000 isn't a valid microsecond
+    /// ```
+    pub const fn replace_microsecond(
+        self,
+        microsecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(microsecond in 0 => 999_999);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            self.second,
+            microsecond * 1_000,
+        ))
+    }
+
+    /// Replace the nanoseconds within the second.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!(01:02:03.004_005_006).replace_nanosecond(7_008_009),
+    ///     Ok(time!(01:02:03.007_008_009))
+    /// );
+    /// assert!(time!(01:02:03.004_005_006).replace_nanosecond(1_000_000_000).is_err()); // 1_000_000_000 isn't a valid nanosecond
+    /// ```
+    pub const fn replace_nanosecond(self, nanosecond: u32) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(nanosecond in 0 => 999_999_999);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            self.second,
+            nanosecond,
+        ))
+    }
+    // endregion replacement
+
+    // region: overflowing arithmetic
+    /// Add the [`Duration`] to the `Time`, returning the signed number of whole days carried along
+    /// with the resulting time. The number of days is negative when the result is on an earlier
+    /// day.
+    ///
+    /// Unlike adding a `Duration` with the `+` operator, this accounts for durations spanning any
+    /// number of days. The number of days is an `i64`, as a `Duration` can span more days than fit
+    /// in an `i32`.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, macros::time};
+    /// assert_eq!(time!(12:00).overflowing_add(2.hours()), (0, time!(14:00)));
+    /// assert_eq!(time!(12:00).overflowing_add(50.hours()), (2, time!(14:00)));
+    /// assert_eq!(time!(12:00).overflowing_add(12.hours()), (1, time!(0:00)));
+    /// assert_eq!(
+    ///     time!(0:00:01).overflowing_add((-2).seconds()),
+    ///     (-1, time!(23:59:59))
+    /// );
+    /// assert_eq!(time!(12:00).overflowing_add((-61).hours()), (-3, time!(23:00)));
+    /// ```
+    pub const fn overflowing_add(self, duration: Duration) -> (i64, Self) {
+        /// The number of nanoseconds in a day.
+        const NANOSECONDS_PER_DAY: i128 = 86_400_000_000_000;
+
+        let nanoseconds = self.nanoseconds_since_midnight() as i128 + duration.whole_nanoseconds();
+        (
+            div_floor!(nanoseconds, NANOSECONDS_PER_DAY) as _,
+            Self::from_nanoseconds_since_midnight_unchecked(
+                nanoseconds.rem_euclid(NANOSECONDS_PER_DAY) as _,
+            ),
+        )
+    }
+    // endregion overflowing arithmetic
+
     // region: arithmetic helpers
     /// Add the sub-day time of the [`Duration`] to the `Time`. Wraps on overflow, returning whether
     /// the date is different.
@@ -453,7 +932,19 @@ impl Time {
 // region: formatting & parsing
 #[cfg(feature = "formatting")]
 impl Time {
-    /// Format the `Time` using the provided [format description](crate::format_description).
+    /// Format the `Time` using the provided [format description](crate::format_description),
+    /// writing the result directly into `output`. The number of bytes written is returned.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// let mut buf = Vec::new();
+    /// assert_eq!(time!(12:00).format_into(&mut buf, &format)?, 8);
+    /// assert_eq!(buf, b"12:00:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -462,6 +953,28 @@ impl Time {
         format.format_into(output, None, Some(self), None)
     }
 
+    /// Format the `Time` using the provided [format description](crate::format_description),
+    /// writing the result into a [`fmt::Write`] implementor such as a `String`.
+    ///
+    /// Unlike [`format_into`](Self::format_into), this does not require the `std` feature.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// let mut buf = String::new();
+    /// time!(12:00).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "12:00:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<(), error::Format> {
+        format.format_into(&mut FmtWriter(output), None, Some(self), None)?;
+        Ok(())
+    }
+
     /// Format the `Time` using the provided [format description](crate::format_description).
     ///
     /// ```rust
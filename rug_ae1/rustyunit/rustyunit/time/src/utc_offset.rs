@@ -1,13 +1,15 @@
 //! The [`UtcOffset`] struct and its associated `impl`s.
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::fmt;
 use core::ops::Neg;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting", feature = "std"))]
 use std::io;
 
 use crate::error;
 #[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::formatting::{FmtWriter, Formattable};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 #[cfg(feature = "local-offset")]
@@ -87,11 +89,15 @@ impl UtcOffset {
         Ok(Self::__from_hms_unchecked(hours, minutes, seconds))
     }
 
-    /// Create a `UtcOffset` representing an offset by the number of seconds provided.
+    /// Create a `UtcOffset` representing an offset by the number of seconds provided. This is the
+    /// inverse of [`UtcOffset::whole_seconds`].
     ///
     /// ```rust
     /// # use time::UtcOffset;
     /// assert_eq!(UtcOffset::from_whole_seconds(3_723)?.as_hms(), (1, 2, 3));
+    /// assert_eq!(UtcOffset::from_whole_seconds(-3_723)?.as_hms(), (-1, -2, -3));
+    /// assert_eq!(UtcOffset::from_whole_seconds(-86_399)?.whole_seconds(), -86_399);
+    /// assert!(UtcOffset::from_whole_seconds(86_400).is_err());
     /// # Ok::<_, time::Error>(())
     /// ```
     pub const fn from_whole_seconds(seconds: i32) -> Result<Self, error::ComponentRange> {
@@ -220,10 +226,58 @@ impl UtcOffset {
     }
     // endregion is_{sign}
 
+    // region: checked arithmetic
+    /// Add the given number of minutes to the offset, returning `None` if the result is out of
+    /// range.
+    ///
+    /// ```rust
+    /// # use time::{macros::offset, UtcOffset};
+    /// assert_eq!(
+    ///     UtcOffset::from_hms(1, 0, 0)?.checked_add_minutes(30),
+    ///     Some(offset!(+1:30))
+    /// );
+    /// assert_eq!(offset!(+1:00).checked_add_minutes(-90), Some(offset!(-0:30)));
+    /// assert_eq!(offset!(+23:59).checked_add_minutes(1), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn checked_add_minutes(self, minutes: i16) -> Option<Self> {
+        match Self::from_whole_seconds(self.whole_seconds() + minutes as i32 * 60) {
+            Ok(offset) => Some(offset),
+            Err(_) => None,
+        }
+    }
+
+    /// Subtract the given number of minutes from the offset, returning `None` if the result is
+    /// out of range.
+    ///
+    /// ```rust
+    /// # use time::macros::offset;
+    /// assert_eq!(offset!(+1:30).checked_sub_minutes(30), Some(offset!(+1:00)));
+    /// assert_eq!(offset!(+0:15).checked_sub_minutes(45), Some(offset!(-0:30)));
+    /// assert_eq!(offset!(-23:59).checked_sub_minutes(1), None);
+    /// ```
+    pub const fn checked_sub_minutes(self, minutes: i16) -> Option<Self> {
+        match Self::from_whole_seconds(self.whole_seconds() - minutes as i32 * 60) {
+            Ok(offset) => Some(offset),
+            Err(_) => None,
+        }
+    }
+    // endregion checked arithmetic
+
     // region: local offset
     /// Attempt to obtain the system's UTC offset at a known moment in time. If the offset cannot be
     /// determined, an error is returned.
     ///
+    /// Only the instant represented by `datetime` is relevant; its offset is ignored. This allows
+    /// querying the offset that applies on either side of a daylight saving time transition.
+    ///
+    /// The offset can only be determined on Unix-like and Windows targets; on any other target
+    /// an error is always returned. On Unix-like targets, an error is also returned if the
+    /// process may have more than one thread running, as the underlying system call is not
+    /// thread-safe in the presence of environment mutations. On Windows, the rules of the
+    /// currently active time zone are applied to the instant, and years before 1601 are not
+    /// supported.
+    ///
     /// ```rust
     /// # use time::{UtcOffset, OffsetDateTime};
     /// let local_offset = UtcOffset::local_offset_at(OffsetDateTime::UNIX_EPOCH);
@@ -258,6 +312,8 @@ impl UtcOffset {
 #[cfg(feature = "formatting")]
 impl UtcOffset {
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -266,6 +322,28 @@ impl UtcOffset {
         format.format_into(output, None, None, Some(self))
     }
 
+    /// Format the `UtcOffset` using the provided [format description](crate::format_description),
+    /// writing the result into a [`fmt::Write`] implementor such as a `String`.
+    ///
+    /// Unlike [`format_into`](Self::format_into), this does not require the `std` feature.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset};
+    /// let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")?;
+    /// let mut buf = String::new();
+    /// offset!(+1).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "+01:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<(), error::Format> {
+        format.format_into(&mut FmtWriter(output), None, None, Some(self))?;
+        Ok(())
+    }
+
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
     ///
     /// ```rust
@@ -288,6 +366,7 @@ impl UtcOffset {
     /// # use time::{format_description, macros::offset, UtcOffset};
     /// let format = format_description::parse("[offset_hour]:[offset_minute]")?;
     /// assert_eq!(UtcOffset::parse("-03:42", &format)?, offset!(-3:42));
+    /// assert_eq!(UtcOffset::parse("-00:30", &format)?, offset!(-0:30));
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn parse(
@@ -298,6 +377,36 @@ impl UtcOffset {
     }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl UtcOffset {
+    /// Format the `UtcOffset` in ISO 8601 notation, `±hh:mm`. The seconds are appended as `:ss`
+    /// when they are nonzero or `include_seconds` is true. UTC is written as `+00:00`, never `Z`.
+    ///
+    /// ```rust
+    /// # use time::macros::offset;
+    /// assert_eq!(offset!(+5:30).to_string_hms(false), "+05:30");
+    /// assert_eq!(offset!(-8).to_string_hms(false), "-08:00");
+    /// assert_eq!(offset!(UTC).to_string_hms(false), "+00:00");
+    /// assert_eq!(offset!(+5:30).to_string_hms(true), "+05:30:00");
+    /// assert_eq!(offset!(-0:00:30).to_string_hms(false), "-00:00:30");
+    /// ```
+    pub fn to_string_hms(self, include_seconds: bool) -> String {
+        let sign = if self.is_negative() { '-' } else { '+' };
+        if include_seconds || self.seconds != 0 {
+            alloc::format!(
+                "{}{:02}:{:02}:{:02}",
+                sign,
+                self.hours.abs(),
+                self.minutes.abs(),
+                self.seconds.abs()
+            )
+        } else {
+            alloc::format!("{}{:02}:{:02}", sign, self.hours.abs(), self.minutes.abs())
+        }
+    }
+}
+
 impl fmt::Display for UtcOffset {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
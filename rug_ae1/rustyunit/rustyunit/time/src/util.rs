@@ -59,6 +59,17 @@ pub const fn days_in_year(year: i32) -> u16 {
     if is_leap_year(year) { 366 } else { 365 }
 }
 
+/// Get the largest valid ordinal day of a given year. This is an alias for [`days_in_year`].
+///
+/// ```rust
+/// # use time::util::max_ordinal;
+/// assert_eq!(max_ordinal(2019), 365);
+/// assert_eq!(max_ordinal(2020), 366);
+/// ```
+pub const fn max_ordinal(year: i32) -> u16 {
+    days_in_year(year)
+}
+
 /// Get the number of weeks in the ISO year.
 ///
 /// The returned value will always be either 52 or 53.
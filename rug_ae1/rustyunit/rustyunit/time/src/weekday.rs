@@ -4,6 +4,8 @@ use core::fmt::{self, Display};
 
 use Weekday::*;
 
+use crate::error;
+
 /// Days of the week.
 ///
 /// As order is dependent on context (Sunday could be either two days after or five days before
@@ -27,6 +29,33 @@ pub enum Weekday {
 }
 
 impl Weekday {
+    /// Get all seven weekdays, starting with Monday.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::all()[0], Weekday::Monday);
+    /// assert_eq!(Weekday::all()[6], Weekday::Sunday);
+    /// ```
+    pub const fn all() -> [Self; 7] {
+        [Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday]
+    }
+
+    /// Iterate over all seven weekdays in order, starting with Monday. For a different first day,
+    /// use [`Iterator::cycle`] with [`Iterator::skip`], or rotate the array from
+    /// [`Weekday::all`].
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// # use std::collections::HashSet;
+    /// assert_eq!(Weekday::iter().next(), Some(Weekday::Monday));
+    /// assert_eq!(Weekday::iter().last(), Some(Weekday::Sunday));
+    /// assert_eq!(Weekday::iter().collect::<HashSet<_>>().len(), 7);
+    /// assert!(Weekday::iter().zip(Weekday::iter().skip(1)).all(|(a, b)| a.next() == b));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Self> {
+        IntoIterator::into_iter(Self::all())
+    }
+
     /// Get the previous weekday.
     ///
     /// ```rust
@@ -111,11 +140,73 @@ impl Weekday {
             Sunday => 0,
         }
     }
-}
 
-impl Display for Weekday {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
+    /// Create a `Weekday` from its one-indexed number of days from Monday. This is the inverse of
+    /// [`Weekday::number_from_monday`].
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_from_monday(1), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::from_number_from_monday(7), Ok(Weekday::Sunday));
+    /// assert!(Weekday::from_number_from_monday(0).is_err());
+    /// assert!(Weekday::from_number_from_monday(8).is_err());
+    /// assert_eq!(Weekday::from_number_from_monday(0).unwrap_err().name(), "weekday");
+    /// ```
+    pub const fn from_number_from_monday(weekday: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(weekday in 1 => 7);
+
+        Ok(Self::all()[weekday as usize - 1])
+    }
+
+    /// Create a `Weekday` from its one-indexed number of days from Sunday. This is the inverse of
+    /// [`Weekday::number_from_sunday`].
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_from_sunday(1), Ok(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_number_from_sunday(2), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::from_number_from_sunday(7), Ok(Weekday::Saturday));
+    /// assert!(Weekday::from_number_from_sunday(0).is_err());
+    /// assert!(Weekday::from_number_from_sunday(8).is_err());
+    /// ```
+    pub const fn from_number_from_sunday(weekday: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(weekday in 1 => 7);
+
+        Ok(Self::all()[(weekday as usize + 5) % 7])
+    }
+
+    /// Get the signed number of days from `self` to the nearest occurrence of `other`. A
+    /// positive value indicates that `other` is later in the week.
+    ///
+    /// The returned value will always be in the range `-3..=3`. As there are an odd number of days
+    /// in a week, there is never a tie: a weekday four days later is instead considered to be
+    /// three days earlier.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::Monday.signed_distance(Weekday::Monday), 0);
+    /// assert_eq!(Weekday::Monday.signed_distance(Weekday::Tuesday), 1);
+    /// assert_eq!(Weekday::Monday.signed_distance(Weekday::Sunday), -1);
+    /// assert_eq!(Weekday::Monday.signed_distance(Weekday::Thursday), 3);
+    /// assert_eq!(Weekday::Monday.signed_distance(Weekday::Friday), -3);
+    /// assert_eq!(Weekday::Sunday.signed_distance(Weekday::Monday), 1);
+    /// ```
+    pub const fn signed_distance(self, other: Self) -> i8 {
+        let distance =
+            (other.number_days_from_monday() as i8 - self.number_days_from_monday() as i8 + 7) % 7;
+        if distance > 3 { distance - 7 } else { distance }
+    }
+
+    /// Get the full English name of the weekday.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::Monday.name(), "Monday");
+    /// assert_eq!(Weekday::Monday.as_ref(), "Monday");
+    /// assert_eq!(Weekday::Monday.to_string(), Weekday::Monday.name());
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
             Monday => "Monday",
             Tuesday => "Tuesday",
             Wednesday => "Wednesday",
@@ -123,6 +214,18 @@ impl Display for Weekday {
             Friday => "Friday",
             Saturday => "Saturday",
             Sunday => "Sunday",
-        })
+        }
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl AsRef<str> for Weekday {
+    fn as_ref(&self) -> &str {
+        self.name()
     }
 }
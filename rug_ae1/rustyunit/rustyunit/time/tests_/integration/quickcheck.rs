@@ -0,0 +1,53 @@
+use quickcheck_dep::{quickcheck, Arbitrary};
+use time::{Duration, Time};
+
+/// Repeatedly replace `value` with the first shrunk candidate that still satisfies `failing`,
+/// mirroring how quickcheck minimizes a counterexample.
+fn minimize(mut value: Duration, failing: impl Fn(Duration) -> bool) -> Duration {
+    while let Some(candidate) = value.shrink().find(|&candidate| failing(candidate)) {
+        value = candidate;
+    }
+    value
+}
+
+#[test]
+fn duration_shrink() {
+    let duration = Duration::new(1_234_567_890, 123_456_789);
+    assert_eq!(duration.shrink().next(), Some(Duration::ZERO));
+    assert!(duration
+        .shrink()
+        .all(|shrunk| shrunk.abs() < duration.abs()));
+    assert_eq!(
+        (-duration).shrink().take(2).collect::<Vec<_>>(),
+        [Duration::ZERO, duration]
+    );
+    assert!(Duration::ZERO.shrink().next().is_none());
+    assert!(Duration::MIN
+        .shrink()
+        .take(1_000)
+        .all(|shrunk| !(shrunk.is_negative() && shrunk.is_positive())));
+
+    assert_eq!(
+        minimize(Duration::MAX, |value| value >= Duration::SECOND),
+        Duration::SECOND
+    );
+    assert_eq!(
+        minimize(Duration::MIN, |value| value <= -Duration::MILLISECOND),
+        -Duration::MILLISECOND
+    );
+    assert_eq!(
+        minimize(duration, |value| value.subsec_nanoseconds() != 0),
+        Duration::NANOSECOND
+    );
+    assert_eq!(
+        minimize(-duration, |value| value.is_negative()),
+        -Duration::NANOSECOND
+    );
+}
+
+quickcheck! {
+    fn time_nanoseconds_since_midnight_roundtrip(time: Time) -> bool {
+        time.nanoseconds_since_midnight() < 86_400_000_000_000
+            && Time::from_nanoseconds_since_midnight(time.nanoseconds_since_midnight()) == Ok(time)
+    }
+}
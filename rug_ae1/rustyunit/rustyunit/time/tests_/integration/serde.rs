@@ -1,6 +1,10 @@
-use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token,
+};
 use time::macros::{date, datetime, offset, time};
-use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use time::{
+    Date, Duration, Instant, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
 
 #[test]
 fn time() {
@@ -732,3 +736,248 @@ fn month_error() {
         "invalid type: boolean `false`, expected a `Month`",
     );
 }
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Iso8601 {
+    #[serde(with = "time::serde::iso8601::date")]
+    date: Date,
+    #[serde(with = "time::serde::iso8601::date::option")]
+    option_date: Option<Date>,
+    #[serde(with = "time::serde::iso8601::time")]
+    time: Time,
+    #[serde(with = "time::serde::iso8601::time::option")]
+    option_time: Option<Time>,
+}
+
+#[test]
+fn iso8601() {
+    let value = Iso8601 {
+        date: date!(2021 - 03 - 04),
+        option_date: None,
+        time: time!(23:58:59.5),
+        option_time: Some(time!(0:00)),
+    };
+    assert_tokens(
+        &value.readable(),
+        &[
+            Token::Struct {
+                name: "Iso8601",
+                len: 4,
+            },
+            Token::Str("date"),
+            Token::BorrowedStr("2021-03-04"),
+            Token::Str("option_date"),
+            Token::None,
+            Token::Str("time"),
+            Token::BorrowedStr("23:58:59.5"),
+            Token::Str("option_time"),
+            Token::Some,
+            Token::BorrowedStr("00:00:00"),
+            Token::StructEnd,
+        ],
+    );
+    assert_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "Iso8601",
+                len: 4,
+            },
+            Token::Str("date"),
+            Token::Tuple { len: 2 },
+            Token::I32(2021),
+            Token::U16(63),
+            Token::TupleEnd,
+            Token::Str("option_date"),
+            Token::None,
+            Token::Str("time"),
+            Token::Tuple { len: 4 },
+            Token::U8(23),
+            Token::U8(58),
+            Token::U8(59),
+            Token::U32(500_000_000),
+            Token::TupleEnd,
+            Token::Str("option_time"),
+            Token::Some,
+            Token::Tuple { len: 4 },
+            Token::U8(0),
+            Token::U8(0),
+            Token::U8(0),
+            Token::U32(0),
+            Token::TupleEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn iso8601_error() {
+    assert_de_tokens_error::<Readable<Iso8601>>(
+        &[
+            Token::Struct {
+                name: "Iso8601",
+                len: 4,
+            },
+            Token::Str("date"),
+            Token::BorrowedStr("2021-13-04"),
+        ],
+        "the 'month' component could not be parsed",
+    );
+    assert_de_tokens_error::<Readable<Iso8601>>(
+        &[
+            Token::Struct {
+                name: "Iso8601",
+                len: 4,
+            },
+            Token::Str("date"),
+            Token::BorrowedStr("2021-03-04"),
+            Token::Str("option_date"),
+            Token::None,
+            Token::Str("time"),
+            Token::BorrowedStr("24:00:00"),
+        ],
+        "hour must be in the range 0..=23",
+    );
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct InstantRelative {
+    #[serde(with = "time::serde::instant_relative")]
+    instant: Instant,
+    #[serde(with = "time::serde::instant_relative::option")]
+    option_instant: Option<Instant>,
+}
+
+// `Instant::now()` is sampled separately when the value is created and when it is deserialized, so
+// instants are only compared to within a second.
+impl PartialEq for InstantRelative {
+    fn eq(&self, other: &Self) -> bool {
+        fn is_close(a: Instant, b: Instant) -> bool {
+            (a - b).abs() < Duration::SECOND
+        }
+
+        is_close(self.instant, other.instant)
+            && match (self.option_instant, other.option_instant) {
+                (Some(a), Some(b)) => is_close(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+#[test]
+fn instant_relative() {
+    let value = InstantRelative {
+        instant: Instant::now() + Duration::minutes(1),
+        option_instant: Some(Instant::now() - Duration::minutes(1)),
+    };
+    assert_de_tokens(
+        &value.readable(),
+        &[
+            Token::Struct {
+                name: "InstantRelative",
+                len: 2,
+            },
+            Token::Str("instant"),
+            Token::BorrowedStr("60.000000000"),
+            Token::Str("option_instant"),
+            Token::Some,
+            Token::BorrowedStr("-60.000000000"),
+            Token::StructEnd,
+        ],
+    );
+    assert_de_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "InstantRelative",
+                len: 2,
+            },
+            Token::Str("instant"),
+            Token::Tuple { len: 2 },
+            Token::I64(60),
+            Token::I32(0),
+            Token::TupleEnd,
+            Token::Str("option_instant"),
+            Token::Some,
+            Token::Tuple { len: 2 },
+            Token::I64(-60),
+            Token::I32(0),
+            Token::TupleEnd,
+            Token::StructEnd,
+        ],
+    );
+    assert_de_tokens(
+        &InstantRelative {
+            instant: Instant::now(),
+            option_instant: None,
+        }
+        .compact(),
+        &[
+            Token::Struct {
+                name: "InstantRelative",
+                len: 2,
+            },
+            Token::Str("instant"),
+            Token::Tuple { len: 2 },
+            Token::I64(0),
+            Token::I32(0),
+            Token::TupleEnd,
+            Token::Str("option_instant"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct InstantRelativeOption {
+    #[serde(with = "time::serde::instant_relative::option")]
+    option_instant: Option<Instant>,
+}
+
+// A present `Instant` is serialized relative to the moment of serialization, so only its absence
+// produces tokens that are known in advance. This checks the serialize path exactly.
+#[test]
+fn instant_relative_option_none() {
+    let value = InstantRelativeOption {
+        option_instant: None,
+    };
+    let tokens = [
+        Token::Struct {
+            name: "InstantRelativeOption",
+            len: 1,
+        },
+        Token::Str("option_instant"),
+        Token::None,
+        Token::StructEnd,
+    ];
+    assert_tokens(&value.compact(), &tokens);
+    assert_tokens(&value.readable(), &tokens);
+}
+
+#[test]
+fn instant_relative_serialize() {
+    /// Serialize the instant using the formatter, which serde treats as a human-readable
+    /// serializer.
+    struct Relative(Instant);
+
+    impl core::fmt::Display for Relative {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            time::serde::instant_relative::serialize(&self.0, f)
+        }
+    }
+
+    // Some time passes before the instant is serialized, so the value is slightly smaller in
+    // magnitude than the offset it was created with.
+    let seconds: f64 = Relative(Instant::now() + Duration::minutes(1))
+        .to_string()
+        .parse()
+        .expect("serialized value is a number of seconds");
+    assert!(59. < seconds && seconds <= 60.);
+    let seconds: f64 = Relative(Instant::now() - Duration::minutes(1))
+        .to_string()
+        .parse()
+        .expect("serialized value is a number of seconds");
+    assert!(-61. < seconds && seconds <= -60.);
+}